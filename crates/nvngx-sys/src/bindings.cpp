@@ -182,6 +182,34 @@ NVSDK_NGX_Result HELPERS_NGX_VULKAN_EVALUATE_DLSS_EXT(
 }
 
 // Ray Reconstruction
+NVSDK_NGX_Result HELPERS_NGX_DLSSD_GET_OPTIMAL_SETTINGS(
+    NVSDK_NGX_Parameter *pInParams,
+    unsigned int InUserSelectedWidth,
+    unsigned int InUserSelectedHeight,
+    NVSDK_NGX_PerfQuality_Value InPerfQualityValue,
+    unsigned int *pOutRenderOptimalWidth,
+    unsigned int *pOutRenderOptimalHeight,
+    unsigned int *pOutRenderMaxWidth,
+    unsigned int *pOutRenderMaxHeight,
+    unsigned int *pOutRenderMinWidth,
+    unsigned int *pOutRenderMinHeight,
+    float *pOutSharpness) {
+
+    return NGX_DLSSD_GET_OPTIMAL_SETTINGS(
+        pInParams,
+        InUserSelectedWidth,
+        InUserSelectedHeight,
+        InPerfQualityValue,
+        pOutRenderOptimalWidth,
+        pOutRenderOptimalHeight,
+        pOutRenderMaxWidth,
+        pOutRenderMaxHeight,
+        pOutRenderMinWidth,
+        pOutRenderMinHeight,
+        pOutSharpness
+    );
+}
+
 NVSDK_NGX_Result HELPERS_NGX_VULKAN_CREATE_DLSSD_EXT1(
     VkDevice InDevice,
     VkCommandBuffer InCmdList,
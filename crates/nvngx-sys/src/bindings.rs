@@ -3932,6 +3932,22 @@ extern "C" {
         pInDlssEvalParams: *mut NVSDK_NGX_VK_DLSS_Eval_Params,
     ) -> NVSDK_NGX_Result;
 }
+extern "C" {
+    #[link_name = "\u{1}_Z38HELPERS_NGX_DLSSD_GET_OPTIMAL_SETTINGSP19NVSDK_NGX_Parameterjj27NVSDK_NGX_PerfQuality_ValuePjS2_S2_S2_S2_S2_Pf"]
+    pub fn HELPERS_NGX_DLSSD_GET_OPTIMAL_SETTINGS(
+        pInParams: *mut NVSDK_NGX_Parameter,
+        InUserSelectedWidth: ::std::os::raw::c_uint,
+        InUserSelectedHeight: ::std::os::raw::c_uint,
+        InPerfQualityValue: NVSDK_NGX_PerfQuality_Value,
+        pOutRenderOptimalWidth: *mut ::std::os::raw::c_uint,
+        pOutRenderOptimalHeight: *mut ::std::os::raw::c_uint,
+        pOutRenderMaxWidth: *mut ::std::os::raw::c_uint,
+        pOutRenderMaxHeight: *mut ::std::os::raw::c_uint,
+        pOutRenderMinWidth: *mut ::std::os::raw::c_uint,
+        pOutRenderMinHeight: *mut ::std::os::raw::c_uint,
+        pOutSharpness: *mut f32,
+    ) -> NVSDK_NGX_Result;
+}
 extern "C" {
     #[link_name = "\u{1}_Z36HELPERS_NGX_VULKAN_CREATE_DLSSD_EXT1P10VkDevice_TP17VkCommandBuffer_TjjPP16NVSDK_NGX_HandleP19NVSDK_NGX_ParameterP29NVSDK_NGX_DLSSD_Create_Params"]
     pub fn HELPERS_NGX_VULKAN_CREATE_DLSSD_EXT1(
@@ -4,7 +4,7 @@ mod vk_mini_init;
 
 use ash::vk;
 use image::ColorType;
-use nvngx::SuperSamplingFeature;
+use nvngx::{SuperSamplingFeature, TemporalUpscalerInputs};
 
 fn main() {
     let required_extensions = nvngx::vk::RequiredExtensions::get().unwrap();
@@ -52,8 +52,8 @@ fn main() {
         src_height,
         dst_width,
         dst_height,
-        Some(nvngx::sys::NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_Balanced),
-        None,
+        Some(nvngx::QualityMode::Balanced),
+        nvngx::DlssFeatureFlags::new(),
     );
 
     let mut ss: nvngx_sys::Result<SuperSamplingFeature> =
@@ -0,0 +1,86 @@
+//! User-provided allocation of the memory requested by the NGX.
+
+use super::*;
+
+/// An allocator of the memory requested by the NGX features.
+///
+/// Once set via [`System::set_allocator`], the [`System`] uses it to
+/// allocate the scratch buffer each feature requests
+/// ([`FeatureParameters::get_scratch_buffer_size`]) before the
+/// feature is created, and frees the buffer once the feature is
+/// dropped. This way the memory shows up in the application's own
+/// memory tracking.
+///
+/// Only buffers are supported, there is no way to provide the images:
+/// the `ResourceAllocCallback`, `BufferAllocCallback` and
+/// `ResourceReleaseCallback` NGX parameters
+/// ([`nvngx_sys::NVSDK_NGX_Parameter_ResourceAllocCallback`] and
+/// others) are only defined for Direct3D resources. With Vulkan, the
+/// NGX allocates the rest of its internal resources, including all the
+/// images, on its own, and those can only be observed via
+/// [`SuperSamplingStats`] and [`RayReconstructionStats`].
+///
+/// The methods take `&self`, as the allocator is shared between the
+/// [`System`] and all the features it creates, so the implementations
+/// should use interior mutability if they need to.
+pub trait FeatureAllocator: std::fmt::Debug {
+    /// Allocates a buffer of at least `size_in_bytes` bytes to be
+    /// used by the NGX. The buffer should be usable as a storage
+    /// buffer and reside in the device-local memory.
+    fn allocate_buffer(&self, size_in_bytes: usize) -> Result<VkBufferResourceDescription>;
+
+    /// Frees the buffer previously allocated by
+    /// [`Self::allocate_buffer`]. The buffer is no longer used by the
+    /// NGX at this point.
+    fn free_buffer(&self, buffer: VkBufferResourceDescription);
+}
+
+/// A scratch buffer allocated by a [`FeatureAllocator`] for a
+/// feature. The buffer is freed using the same allocator when dropped.
+#[derive(Debug)]
+pub struct ScratchBuffer {
+    /// The NGX passes around the pointer to this resource, so it must
+    /// have a stable address.
+    resource: Box<NVSDK_NGX_Resource_VK>,
+    description: VkBufferResourceDescription,
+    allocator: Rc<dyn FeatureAllocator>,
+}
+
+impl ScratchBuffer {
+    /// Allocates a new scratch buffer of `size_in_bytes` bytes using
+    /// the `allocator`.
+    pub(crate) fn new(allocator: Rc<dyn FeatureAllocator>, size_in_bytes: usize) -> Result<Self> {
+        let mut description = allocator.allocate_buffer(size_in_bytes)?;
+        if description.size_in_bytes < size_in_bytes {
+            allocator.free_buffer(description);
+            return Err(nvngx_sys::Error::Other(format!(
+                "The allocator provided a scratch buffer of {} bytes, while {size_in_bytes} bytes were requested.",
+                description.size_in_bytes
+            )));
+        }
+        // The NGX writes to the scratch buffer.
+        description.mode = VkResourceMode::Writable;
+
+        Ok(Self {
+            resource: Box::new(description.into()),
+            description,
+            allocator,
+        })
+    }
+
+    /// Returns the description of the buffer.
+    pub fn get_description(&self) -> &VkBufferResourceDescription {
+        &self.description
+    }
+
+    /// Returns the NGX resource pointing to the buffer.
+    pub(crate) fn get_resource_mut(&mut self) -> *mut NVSDK_NGX_Resource_VK {
+        std::ptr::addr_of_mut!(*self.resource)
+    }
+}
+
+impl Drop for ScratchBuffer {
+    fn drop(&mut self) {
+        self.allocator.free_buffer(self.description);
+    }
+}
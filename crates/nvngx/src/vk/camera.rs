@@ -0,0 +1,85 @@
+//! The camera matrices some of the features require.
+
+/// A 4x4 camera matrix of [`f32`], the 16 values of which are passed
+/// to the NGX in their memory order: `matrix[0][0]`, `matrix[0][1]`
+/// and so on. The `mint` and `glam` conversions keep the columns of
+/// their matrices as the inner arrays (`matrix[column][row]`).
+///
+/// The SDK headers don't state the layout the NGX expects these
+/// values in, see the DLSS Ray Reconstruction programming guide.
+///
+/// Converts from the `[[f32; 4]; 4]` of the same layout, and, with the
+/// `mint` or `glam` features enabled, from the
+/// `mint::ColumnMatrix4<f32>` and `glam::Mat4`.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct CameraMatrix(pub [[f32; 4]; 4]);
+
+impl CameraMatrix {
+    /// The identity matrix.
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    /// Returns the pointer to the first of the 16 values, the way the
+    /// NGX expects the matrices.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut f32 {
+        self.0.as_mut_ptr().cast()
+    }
+}
+
+impl From<[[f32; 4]; 4]> for CameraMatrix {
+    fn from(value: [[f32; 4]; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CameraMatrix> for [[f32; 4]; 4] {
+    fn from(value: CameraMatrix) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix4<f32>> for CameraMatrix {
+    fn from(value: mint::ColumnMatrix4<f32>) -> Self {
+        Self(value.into())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Mat4> for CameraMatrix {
+    fn from(value: glam::Mat4) -> Self {
+        Self(value.to_cols_array_2d())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CameraMatrix;
+
+    #[test]
+    fn values_are_passed_in_memory_order() {
+        let mut matrix = CameraMatrix::IDENTITY;
+        matrix.0[3][0] = 5.0;
+        let values = unsafe { std::slice::from_raw_parts(matrix.as_mut_ptr(), 16) };
+        assert_eq!(values[12], 5.0);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn from_glam() {
+        let matrix = glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(CameraMatrix::from(matrix).0[3], [1.0, 2.0, 3.0, 1.0]);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn from_mint() {
+        let matrix: mint::ColumnMatrix4<f32> = CameraMatrix::IDENTITY.0.into();
+        assert_eq!(CameraMatrix::from(matrix), CameraMatrix::IDENTITY);
+    }
+}
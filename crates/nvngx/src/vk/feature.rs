@@ -3,13 +3,20 @@
 use super::*;
 
 /// An NGX handle. Handle might be created and used by [`Feature::new()`].
-#[repr(transparent)]
+///
+/// Owns the scratch buffer provided to the feature (if any), so that
+/// the buffer lives as long as the feature does, however long the
+/// handle is shared. The buffer is dropped (freed) after the handle is
+/// released.
 #[derive(Debug)]
-pub struct FeatureHandle(pub(crate) *mut nvngx_sys::NVSDK_NGX_Handle);
+pub struct FeatureHandle(
+    pub(crate) *mut nvngx_sys::NVSDK_NGX_Handle,
+    pub(crate) Option<ScratchBuffer>,
+);
 
 impl Default for FeatureHandle {
     fn default() -> Self {
-        Self(std::ptr::null_mut())
+        Self(std::ptr::null_mut(), None)
     }
 }
 
@@ -462,6 +469,68 @@ impl FeatureParameters {
             .unwrap_or(false)
     }
 
+    /// Returns the number of bytes needed for the scratch buffer for
+    /// the feature of the `feature_type`, created with these
+    /// parameters.
+    ///
+    /// # NVIDIA documentation
+    ///
+    /// SDK needs a buffer of a certain size provided by the client in
+    /// order to initialize AI feature. Once feature is no longer
+    /// needed buffer can be released. It is safe to reuse the same
+    /// scratch buffer for different features as long as minimum size
+    /// requirement is met for all features. Please note that some
+    /// features might not need a scratch buffer so return size of 0
+    /// is completely valid.
+    pub fn get_scratch_buffer_size(&self, feature_type: NVSDK_NGX_Feature) -> Result<usize> {
+        let mut size = 0usize;
+        Result::from(unsafe {
+            nvngx_sys::NVSDK_NGX_VULKAN_GetScratchBufferSize(
+                feature_type,
+                self.0 as _,
+                &mut size as *mut _,
+            )
+        })
+        .map(|_| size)
+    }
+
+    /// Returns a callback function pointer stored in the parameter
+    /// named `name`, if the NGX has provided one.
+    ///
+    /// The callbacks are only provided in the parameter maps obtained
+    /// via [`Self::get_capability_parameters`] and only by the DLSS
+    /// versions which support them.
+    pub(crate) fn get_callback(
+        &self,
+        name: &FeatureParameterName,
+    ) -> Result<*mut std::ffi::c_void> {
+        match self.get_ptr(name) {
+            Ok(ptr) if !ptr.is_null() => Ok(ptr),
+            // Same as the NGX helpers do: the installed DLSS is either
+            // out of date or the parameters weren't obtained properly.
+            _ => Err(nvngx_sys::Error::Internal(
+                nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+            )),
+        }
+    }
+
+    /// Invokes the "get stats" callback stored in the parameter named
+    /// `callback_name` and returns the amount of video memory (in
+    /// bytes) it reports as allocated.
+    pub(crate) fn get_allocated_vram_bytes(
+        &self,
+        callback_name: &FeatureParameterName,
+    ) -> Result<u64> {
+        let callback = self.get_callback(callback_name)?;
+        let callback: nvngx_sys::PFN_NVSDK_NGX_DLSS_GetStatsCallback =
+            unsafe { std::mem::transmute(callback) };
+        let callback = callback.ok_or(nvngx_sys::Error::Internal(
+            nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+        ))?;
+        Result::from(unsafe { callback(self.0) })?;
+        self.get_u64(nvngx_sys::NVSDK_NGX_Parameter_SizeInBytes)
+    }
+
     /// Deallocates the feature parameter set.
     fn release(&self) -> Result {
         unsafe { nvngx_sys::NVSDK_NGX_VULKAN_DestroyParameters(self.0) }.into()
@@ -524,11 +593,32 @@ impl Feature {
     ) -> Result<SuperSamplingFeature> {
         let feature_type = NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling;
         let rendering_resolution = vk::Extent2D::default()
-            .width(super_sampling_create_parameters.0.Feature.InWidth)
-            .height(super_sampling_create_parameters.0.Feature.InHeight);
+            .width(super_sampling_create_parameters.parameters.Feature.InWidth)
+            .height(super_sampling_create_parameters.parameters.Feature.InHeight);
         let target_resolution = vk::Extent2D::default()
-            .width(super_sampling_create_parameters.0.Feature.InTargetWidth)
-            .height(super_sampling_create_parameters.0.Feature.InTargetHeight);
+            .width(
+                super_sampling_create_parameters
+                    .parameters
+                    .Feature
+                    .InTargetWidth,
+            )
+            .height(
+                super_sampling_create_parameters
+                    .parameters
+                    .Feature
+                    .InTargetHeight,
+            );
+        if super_sampling_create_parameters.get_quality_level()? == QualityMode::Dlaa
+            && rendering_resolution != target_resolution
+        {
+            return Err(nvngx_sys::Error::Other(format!(
+                "The DLAA requires the rendering resolution ({}x{}) to be equal to the target resolution ({}x{}).",
+                rendering_resolution.width,
+                rendering_resolution.height,
+                target_resolution.width,
+                target_resolution.height
+            )));
+        }
         unsafe {
             let mut handle = FeatureHandle::new();
             Result::from(nvngx_sys::HELPERS_NGX_VULKAN_CREATE_DLSS_EXT1(
@@ -538,7 +628,7 @@ impl Feature {
                 1,
                 &mut handle.0 as *mut _,
                 parameters.0,
-                &mut super_sampling_create_parameters.0 as *mut _,
+                &mut super_sampling_create_parameters.parameters as *mut _,
             ))
             .and_then(|_| {
                 SuperSamplingFeature::new(
@@ -547,8 +637,7 @@ impl Feature {
                         feature_type,
                         parameters: parameters.into(),
                     },
-                    rendering_resolution,
-                    target_resolution,
+                    &super_sampling_create_parameters,
                 )
             })
         }
@@ -572,13 +661,7 @@ impl Feature {
         mut ray_reconstruction_create_parameters: RayReconstructionCreateParameters,
     ) -> Result<RayReconstructionFeature> {
         let feature_type = NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction;
-        let rendering_resolution = vk::Extent2D::default()
-            .width(ray_reconstruction_create_parameters.0.InWidth)
-            .height(ray_reconstruction_create_parameters.0.InHeight);
-        let target_resolution = vk::Extent2D::default()
-            .width(ray_reconstruction_create_parameters.0.InTargetWidth)
-            .height(ray_reconstruction_create_parameters.0.InTargetHeight);
-
+        ray_reconstruction_create_parameters.check_flags()?;
         unsafe {
             let mut handle = FeatureHandle::new();
             Result::from(nvngx_sys::HELPERS_NGX_VULKAN_CREATE_DLSSD_EXT1(
@@ -597,8 +680,7 @@ impl Feature {
                         feature_type,
                         parameters: parameters.into(),
                     },
-                    rendering_resolution,
-                    target_resolution,
+                    &ray_reconstruction_create_parameters,
                 )
             })
         }
@@ -637,25 +719,21 @@ impl Feature {
     /// Returns the number of bytes needed for the scratch buffer for
     /// this feature.
     ///
-    /// # NVIDIA documentation
-    ///
-    /// SDK needs a buffer of a certain size provided by the client in
-    /// order to initialize AI feature. Once feature is no longer
-    /// needed buffer can be released. It is safe to reuse the same
-    /// scratch buffer for different features as long as minimum size
-    /// requirement is met for all features. Please note that some
-    /// features might not need a scratch buffer so return size of 0
-    /// is completely valid.
+    /// See [`FeatureParameters::get_scratch_buffer_size`].
     pub fn get_scratch_buffer_size(&self) -> Result<usize> {
-        let mut size = 0usize;
-        Result::from(unsafe {
-            nvngx_sys::NVSDK_NGX_VULKAN_GetScratchBufferSize(
-                self.feature_type,
-                self.parameters.0 as _,
-                &mut size as *mut _,
-            )
-        })
-        .map(|_| size)
+        self.parameters.get_scratch_buffer_size(self.feature_type)
+    }
+
+    /// Returns the scratch buffer allocated for this feature by the
+    /// [`FeatureAllocator`] of the [`System`], if any.
+    pub fn get_scratch_buffer(&self) -> Option<&ScratchBuffer> {
+        self.handle.1.as_ref()
+    }
+
+    /// Hands the `scratch_buffer` the feature was created with over to
+    /// its handle, which frees it after the feature is released.
+    pub(crate) fn set_scratch_buffer(&mut self, scratch_buffer: Option<ScratchBuffer>) {
+        Rc::get_mut(&mut self.handle).unwrap().1 = scratch_buffer;
     }
 
     /// Evalutes the feature.
@@ -0,0 +1,82 @@
+//! The Frame Generation feature.
+
+use super::*;
+
+/// The formats of the buffers used by the Frame Generation feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FrameGenerationBufferFormats {
+    /// The format of the color (back) buffer.
+    pub color: vk::Format,
+    /// The format of the motion vectors buffer.
+    pub motion_vectors: vk::Format,
+    /// The format of the depth buffer.
+    pub depth: vk::Format,
+    /// The format of the colour buffer without the user interface
+    /// ("HUD-less"). [`vk::Format::UNDEFINED`] if not used.
+    pub hudless: vk::Format,
+    /// The format of the user interface buffer.
+    /// [`vk::Format::UNDEFINED`] if not used.
+    pub ui: vk::Format,
+}
+
+/// An estimate of the video memory the Frame Generation feature
+/// requires for the given resolutions and buffer formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FrameGenerationVramEstimate {
+    /// The resolution of the motion vectors and the depth buffers.
+    pub motion_vectors_depth_resolution: vk::Extent2D,
+    /// The resolution of the color (back) buffer.
+    pub color_resolution: vk::Extent2D,
+    /// The formats of the buffers used.
+    pub formats: FrameGenerationBufferFormats,
+    /// The estimated amount of video memory in bytes.
+    pub estimated_vram_bytes: usize,
+}
+
+impl FrameGenerationVramEstimate {
+    /// Returns an estimate of the video memory the Frame Generation
+    /// feature requires, using the
+    /// [`nvngx_sys::NVSDK_NGX_Parameter_DLSSGEstimateVRAMCallback`].
+    ///
+    /// Unlike [`SuperSamplingVramEstimate`], this doesn't create the
+    /// feature to measure it. The `parameters` must be obtained via
+    /// [`FeatureParameters::get_capability_parameters`], otherwise the
+    /// callback isn't available.
+    pub fn get_vram_estimate(
+        parameters: &FeatureParameters,
+        motion_vectors_depth_resolution: vk::Extent2D,
+        color_resolution: vk::Extent2D,
+        formats: FrameGenerationBufferFormats,
+    ) -> Result<Self> {
+        let callback =
+            parameters.get_callback(nvngx_sys::NVSDK_NGX_Parameter_DLSSGEstimateVRAMCallback)?;
+        let callback: nvngx_sys::PFN_NVSDK_NGX_DLSSG_EstimateVRAMCallback =
+            unsafe { std::mem::transmute(callback) };
+        let callback = callback.ok_or(nvngx_sys::Error::Internal(
+            nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+        ))?;
+
+        let mut estimated_vram_bytes = 0usize;
+        Result::from(unsafe {
+            callback(
+                motion_vectors_depth_resolution.width,
+                motion_vectors_depth_resolution.height,
+                color_resolution.width,
+                color_resolution.height,
+                formats.color.as_raw() as u32,
+                formats.motion_vectors.as_raw() as u32,
+                formats.depth.as_raw() as u32,
+                formats.hudless.as_raw() as u32,
+                formats.ui.as_raw() as u32,
+                &mut estimated_vram_bytes as *mut _,
+            )
+        })?;
+
+        Ok(Self {
+            motion_vectors_depth_resolution,
+            color_resolution,
+            formats,
+            estimated_vram_bytes,
+        })
+    }
+}
@@ -0,0 +1,228 @@
+//! The sub-pixel jitter of the rendered frames, which the temporal
+//! upscalers rely on.
+
+use super::*;
+
+/// The number of jitter phases recommended per a pixel of the target
+/// resolution covered by a rendered pixel.
+const PHASES_PER_UPSCALED_PIXEL: f32 = 8.0;
+
+/// Returns the element of the Halton low-discrepancy sequence with the
+/// `base` at the `index` (starting from `1`), in the range `[0, 1)`.
+pub fn halton(mut index: u32, base: u32) -> f32 {
+    let mut fraction = 1.0f32;
+    let mut result = 0.0f32;
+    while index > 0 {
+        fraction /= base as f32;
+        result += fraction * (index % base) as f32;
+        index /= base;
+    }
+    result
+}
+
+/// A generator of the sub-pixel jitter offsets using the Halton(2, 3)
+/// sequence, with the number of phases matching the scale factor of
+/// the upscaling: `8 × (target / render)²`, as recommended by the DLSS
+/// programming guide.
+///
+/// The offsets are in the pixel space of the render resolution, in the
+/// range `[-0.5, 0.5)`, with the Y axis pointing down, and are meant
+/// to be passed as is to the
+/// [`TemporalUpscalerInputs::set_jitter_offsets`] and to
+/// [`apply_jitter_to_projection`], so that the same convention is used
+/// for both.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct JitterSequence {
+    phase_count: u32,
+    index: u32,
+}
+
+impl JitterSequence {
+    /// Creates a new jitter sequence for the upscaling from the
+    /// `rendering_resolution` to the `target_resolution`.
+    pub fn new(rendering_resolution: vk::Extent2D, target_resolution: vk::Extent2D) -> Self {
+        Self {
+            phase_count: Self::get_phase_count_for(rendering_resolution, target_resolution),
+            index: 0,
+        }
+    }
+
+    /// Creates a new jitter sequence matching the render resolution of
+    /// the current frame ([`SuperSamplingFeature::get_render_size`])
+    /// and the target resolution of the `feature`.
+    pub fn from_super_sampling_feature(feature: &SuperSamplingFeature) -> Self {
+        Self::from_temporal_upscaler(feature)
+    }
+
+    /// Creates a new jitter sequence matching the render resolution of
+    /// the current frame ([`TemporalUpscaler::get_render_size`]) and
+    /// the target resolution of the `upscaler`.
+    pub fn from_temporal_upscaler(upscaler: &dyn TemporalUpscaler) -> Self {
+        Self::new(upscaler.get_render_size(), upscaler.get_target_resolution())
+    }
+
+    /// Updates the phase count for the new resolutions, for example,
+    /// every frame when the render resolution changes dynamically
+    /// ([`SuperSamplingFeature::set_render_size`]). The sequence
+    /// continues from the current phase (wrapped around the new phase
+    /// count), rather than starting over.
+    pub fn set_resolutions(
+        &mut self,
+        rendering_resolution: vk::Extent2D,
+        target_resolution: vk::Extent2D,
+    ) {
+        self.phase_count = Self::get_phase_count_for(rendering_resolution, target_resolution);
+        self.index %= self.phase_count;
+    }
+
+    /// Updates the phase count for the current resolutions of the
+    /// `upscaler`, see [`Self::set_resolutions`].
+    pub fn update_from_temporal_upscaler(&mut self, upscaler: &dyn TemporalUpscaler) {
+        self.set_resolutions(upscaler.get_render_size(), upscaler.get_target_resolution());
+    }
+
+    /// Returns the number of phases recommended for the upscaling from
+    /// the `rendering_resolution` to the `target_resolution`.
+    fn get_phase_count_for(
+        rendering_resolution: vk::Extent2D,
+        target_resolution: vk::Extent2D,
+    ) -> u32 {
+        let scale_factor = if rendering_resolution.width == 0 {
+            1.0
+        } else {
+            target_resolution.width as f32 / rendering_resolution.width as f32
+        };
+        let phase_count = (PHASES_PER_UPSCALED_PIXEL * scale_factor * scale_factor).ceil() as u32;
+        phase_count.max(1)
+    }
+
+    /// Returns the number of phases after which the sequence repeats.
+    pub const fn get_phase_count(&self) -> u32 {
+        self.phase_count
+    }
+
+    /// Returns the index of the phase the next offset is taken from.
+    pub const fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the jitter offset of the phase at the `index` (wrapped
+    /// around the phase count), in pixels.
+    pub fn get_offset(&self, index: u32) -> [f32; 2] {
+        let index = index % self.phase_count + 1;
+        [halton(index, 2) - 0.5, halton(index, 3) - 0.5]
+    }
+
+    /// Returns the jitter offset of the current frame, in pixels, and
+    /// advances to the next phase.
+    pub fn next_offset(&mut self) -> [f32; 2] {
+        let offset = self.get_offset(self.index);
+        self.index = (self.index + 1) % self.phase_count;
+        offset
+    }
+
+    /// Starts the sequence over.
+    pub fn reset(&mut self) {
+        self.index = 0;
+    }
+}
+
+/// Returns the `projection` matrix translated by the `jitter` offset
+/// (in pixels, as returned by [`JitterSequence::next_offset`]) in the
+/// Vulkan clip space, where the Y axis points down, so that the
+/// rendered image is shifted by exactly the offset passed to the DLSS.
+///
+/// The matrix is column-major (`projection[column][row]`), the way the
+/// most of the math libraries store it, and may be a perspective or an
+/// orthographic projection. The `render_size` is the resolution the
+/// frame is rendered in.
+pub fn apply_jitter_to_projection(
+    projection: [[f32; 4]; 4],
+    jitter: [f32; 2],
+    render_size: vk::Extent2D,
+) -> [[f32; 4]; 4] {
+    // One pixel is 2 / size in the normalised device coordinates.
+    let offset_x = 2.0 * jitter[0] / render_size.width as f32;
+    let offset_y = 2.0 * jitter[1] / render_size.height as f32;
+
+    // Premultiply by the translation matrix, so that the offset is
+    // scaled by the clip-space `w` and survives the perspective
+    // division.
+    let mut jittered = projection;
+    for column in &mut jittered {
+        column[0] += offset_x * column[3];
+        column[1] += offset_y * column[3];
+    }
+    jittered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_jitter_to_projection, halton, JitterSequence};
+    use ash::vk;
+
+    #[test]
+    fn halton_sequence() {
+        assert_eq!(halton(1, 2), 0.5);
+        assert_eq!(halton(2, 2), 0.25);
+        assert_eq!(halton(3, 2), 0.75);
+        assert!((halton(1, 3) - 1.0 / 3.0).abs() < f32::EPSILON);
+        assert!((halton(2, 3) - 2.0 / 3.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn phase_count_matches_scale_factor() {
+        let target = vk::Extent2D::default().width(3840).height(2160);
+        let phase_count = |width, height| {
+            JitterSequence::new(vk::Extent2D::default().width(width).height(height), target)
+                .get_phase_count()
+        };
+        assert_eq!(phase_count(3840, 2160), 8);
+        assert_eq!(phase_count(2560, 1440), 18);
+        assert_eq!(phase_count(1920, 1080), 32);
+        assert_eq!(phase_count(1280, 720), 72);
+    }
+
+    #[test]
+    fn phase_count_follows_render_size() {
+        let target = vk::Extent2D::default().width(3840).height(2160);
+        let mut sequence =
+            JitterSequence::new(vk::Extent2D::default().width(1920).height(1080), target);
+        for _ in 0..20 {
+            sequence.next_offset();
+        }
+        sequence.set_resolutions(vk::Extent2D::default().width(2560).height(1440), target);
+        assert_eq!(sequence.get_phase_count(), 18);
+        assert_eq!(sequence.get_index(), 2);
+    }
+
+    #[test]
+    fn sequence_wraps_around() {
+        let size = vk::Extent2D::default().width(100).height(100);
+        let mut sequence = JitterSequence::new(size, size);
+        let first = sequence.next_offset();
+        assert_eq!(first[0], 0.0);
+        assert!((first[1] + 1.0 / 6.0).abs() < f32::EPSILON);
+        for _ in 1..sequence.get_phase_count() {
+            let offset = sequence.next_offset();
+            assert!((-0.5..0.5).contains(&offset[0]) && (-0.5..0.5).contains(&offset[1]));
+        }
+        assert_eq!(sequence.next_offset(), first);
+    }
+
+    #[test]
+    fn projection_offset_in_clip_space() {
+        let size = vk::Extent2D::default().width(100).height(50);
+        let mut perspective = [[0.0f32; 4]; 4];
+        perspective[0][0] = 1.0;
+        perspective[1][1] = 1.0;
+        perspective[2][2] = 1.0;
+        perspective[2][3] = 1.0;
+
+        let jittered = apply_jitter_to_projection(perspective, [0.5, -0.25], size);
+        assert_eq!(jittered[2][0], 0.01);
+        assert_eq!(jittered[2][1], -0.01);
+        assert_eq!(jittered[0][0], 1.0);
+        assert_eq!(jittered[3][0], 0.0);
+    }
+}
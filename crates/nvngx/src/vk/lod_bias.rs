@@ -0,0 +1,81 @@
+//! The texture level of detail (mip) bias of the rendering in the
+//! lower resolution.
+
+use super::*;
+
+/// The additional bias recommended by the DLSS programming guide on top
+/// of the difference in the resolutions, keeping the textures sharp
+/// after the upscaling.
+const ADDITIONAL_LOD_BIAS: f32 = -1.0;
+
+/// Returns the texture LOD bias recommended for the rendering in the
+/// `rendering_resolution` which is then upscaled to the
+/// `target_resolution`: `log2(render / target) - 1.0`, computed from
+/// the widths. For example, `-2.0` for the upscaling by the factor of
+/// two.
+///
+/// Without the bias, the textures are sampled from the mips matching
+/// the render resolution, and the upscaled image looks blurrier the
+/// lower the quality level is.
+pub fn get_recommended_lod_bias(
+    rendering_resolution: vk::Extent2D,
+    target_resolution: vk::Extent2D,
+) -> f32 {
+    if rendering_resolution.width == 0 || target_resolution.width == 0 {
+        return ADDITIONAL_LOD_BIAS;
+    }
+    (rendering_resolution.width as f32 / target_resolution.width as f32).log2()
+        + ADDITIONAL_LOD_BIAS
+}
+
+/// Returns the `sampler_create_info` with the `lod_bias` added to its
+/// [`vk::SamplerCreateInfo::mip_lod_bias`], so that any bias already
+/// set (for example, an artistic one) is kept.
+///
+/// Note that the resulting bias is clamped by the device to its
+/// [`vk::PhysicalDeviceLimits::max_sampler_lod_bias`].
+pub fn apply_lod_bias(
+    mut sampler_create_info: vk::SamplerCreateInfo<'_>,
+    lod_bias: f32,
+) -> vk::SamplerCreateInfo<'_> {
+    sampler_create_info.mip_lod_bias += lod_bias;
+    sampler_create_info
+}
+
+/// Returns the create info of a trilinear sampler with the `lod_bias`
+/// set and the level of detail unclamped, suitable for sampling the
+/// material textures when rendering in the lower resolution.
+pub fn create_biased_sampler_info(lod_bias: f32) -> vk::SamplerCreateInfo<'static> {
+    vk::SamplerCreateInfo::default()
+        .mag_filter(vk::Filter::LINEAR)
+        .min_filter(vk::Filter::LINEAR)
+        .mipmap_mode(vk::SamplerMipmapMode::LINEAR)
+        .address_mode_u(vk::SamplerAddressMode::REPEAT)
+        .address_mode_v(vk::SamplerAddressMode::REPEAT)
+        .address_mode_w(vk::SamplerAddressMode::REPEAT)
+        .mip_lod_bias(lod_bias)
+        .max_lod(vk::LOD_CLAMP_NONE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_lod_bias, get_recommended_lod_bias};
+    use ash::vk;
+
+    #[test]
+    fn recommended_lod_bias() {
+        let target = vk::Extent2D::default().width(3840).height(2160);
+        let lod_bias = |width, height| {
+            get_recommended_lod_bias(vk::Extent2D::default().width(width).height(height), target)
+        };
+        assert_eq!(lod_bias(3840, 2160), -1.0);
+        assert_eq!(lod_bias(1920, 1080), -2.0);
+        assert!((lod_bias(2560, 1440) - (-1.584_962_5)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn lod_bias_is_added() {
+        let info = vk::SamplerCreateInfo::default().mip_lod_bias(0.5);
+        assert_eq!(apply_lod_bias(info, -2.0).mip_lod_bias, -1.5);
+    }
+}
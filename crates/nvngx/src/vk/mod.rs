@@ -4,17 +4,36 @@ use std::rc::Rc;
 
 use ash::vk;
 use nvngx_sys::{
-    NVSDK_NGX_Coordinates, NVSDK_NGX_Dimensions, NVSDK_NGX_Feature, NVSDK_NGX_ImageViewInfo_VK,
-    NVSDK_NGX_PerfQuality_Value, NVSDK_NGX_Resource_VK, NVSDK_NGX_Resource_VK_Type,
+    NVSDK_NGX_BufferInfo_VK, NVSDK_NGX_Coordinates, NVSDK_NGX_Dimensions, NVSDK_NGX_Feature,
+    NVSDK_NGX_ImageViewInfo_VK, NVSDK_NGX_Resource_VK, NVSDK_NGX_Resource_VK_Type,
     NVSDK_NGX_Resource_VK__bindgen_ty_1, Result,
 };
 
+pub mod allocator;
+pub use allocator::*;
 pub mod feature;
 pub use feature::*;
 pub mod super_sampling;
 pub use super_sampling::*;
 pub mod ray_reconstruction;
 pub use ray_reconstruction::*;
+pub mod quality_mode;
+pub use quality_mode::*;
+pub mod frame_generation;
+pub use frame_generation::*;
+pub mod upscaler;
+pub use upscaler::*;
+pub mod jitter;
+pub use jitter::*;
+pub mod lod_bias;
+pub use lod_bias::*;
+pub mod motion_vectors;
+pub use motion_vectors::*;
+pub mod temporal_upscaler;
+pub use temporal_upscaler::*;
+pub mod camera;
+pub mod validation;
+pub use camera::*;
 
 fn convert_slice_of_strings_to_cstrings(data: &[String]) -> Result<Vec<std::ffi::CString>> {
     let strings: Vec<_> = data
@@ -95,10 +114,10 @@ impl RequiredExtensions {
 }
 
 /// NVIDIA NGX system.
-#[repr(transparent)]
 #[derive(Debug)]
 pub struct System {
     device: vk::Device,
+    allocator: Option<Rc<dyn FeatureAllocator>>,
 }
 
 impl System {
@@ -136,6 +155,7 @@ impl System {
         })
         .map(|_| Self {
             device: logical_device,
+            allocator: None,
         })
     }
 
@@ -143,6 +163,78 @@ impl System {
         unsafe { nvngx_sys::NVSDK_NGX_VULKAN_Shutdown1(self.device) }.into()
     }
 
+    /// Sets the allocator used to provide the memory requested by the
+    /// features created by this system. When no allocator is set, the
+    /// NGX allocates all the memory it needs on its own.
+    ///
+    /// See [`FeatureAllocator`].
+    pub fn set_allocator(&mut self, allocator: Option<Rc<dyn FeatureAllocator>>) {
+        self.allocator = allocator;
+    }
+
+    /// Returns the allocator used by this system, if any.
+    pub fn get_allocator(&self) -> Option<&Rc<dyn FeatureAllocator>> {
+        self.allocator.as_ref()
+    }
+
+    /// Allocates the scratch buffer the feature of the `feature_type`
+    /// requires using the allocator set, and passes it to the NGX via
+    /// the `parameters`.
+    fn provision_scratch_buffer(
+        &self,
+        feature_type: nvngx_sys::NVSDK_NGX_Feature,
+        parameters: &FeatureParameters,
+    ) -> Result<Option<ScratchBuffer>> {
+        let Some(allocator) = self.allocator.as_ref() else {
+            return Ok(None);
+        };
+
+        let size_in_bytes = parameters.get_scratch_buffer_size(feature_type)?;
+        if size_in_bytes == 0 {
+            return Ok(None);
+        }
+
+        let mut scratch_buffer = ScratchBuffer::new(allocator.clone(), size_in_bytes)?;
+        parameters.set_ptr(
+            nvngx_sys::NVSDK_NGX_Parameter_Scratch,
+            scratch_buffer.get_resource_mut(),
+        );
+        parameters.set_u64(
+            nvngx_sys::NVSDK_NGX_Parameter_Scratch_SizeInBytes,
+            size_in_bytes as u64,
+        );
+        Ok(Some(scratch_buffer))
+    }
+
+    /// Returns the amount of video memory (in bytes) a feature of the
+    /// `feature_type` allocates: the difference of what the "get stats"
+    /// callback named `stats_callback_name` reports before and after
+    /// the feature is created by `create`, plus the scratch buffer
+    /// provided by the allocator (if any). The feature is released
+    /// right after.
+    pub(crate) fn measure_feature_vram_bytes<T>(
+        &self,
+        feature_type: nvngx_sys::NVSDK_NGX_Feature,
+        stats_callback_name: &FeatureParameterName,
+        create: impl FnOnce(FeatureParameters) -> Result<T>,
+    ) -> Result<u64> {
+        let capability_parameters = FeatureParameters::get_capability_parameters()?;
+        let allocated_before =
+            capability_parameters.get_allocated_vram_bytes(stats_callback_name)?;
+        let scratch_buffer_size = if self.allocator.is_some() {
+            capability_parameters.get_scratch_buffer_size(feature_type)? as u64
+        } else {
+            0
+        };
+
+        let feature = create(FeatureParameters::get_capability_parameters()?)?;
+        let allocated_after =
+            capability_parameters.get_allocated_vram_bytes(stats_callback_name)?;
+        drop(feature);
+
+        Ok(allocated_after.saturating_sub(allocated_before) + scratch_buffer_size)
+    }
+
     /// Creates a new [`Feature`] with the logical device used to create
     /// this [`System`].
     pub fn create_feature(
@@ -155,7 +247,11 @@ impl System {
             Some(p) => p,
             None => FeatureParameters::get_capability_parameters()?,
         };
-        Feature::new(self.device, command_buffer, feature_type, parameters)
+        let scratch_buffer = self.provision_scratch_buffer(feature_type, &parameters)?;
+        Feature::new(self.device, command_buffer, feature_type, parameters).map(|mut feature| {
+            feature.set_scratch_buffer(scratch_buffer);
+            feature
+        })
     }
 
     /// Creates a supersampling (or "DLSS") feature.
@@ -165,12 +261,20 @@ impl System {
         feature_parameters: FeatureParameters,
         create_parameters: SuperSamplingCreateParameters,
     ) -> Result<SuperSamplingFeature> {
+        let scratch_buffer = self.provision_scratch_buffer(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+            &feature_parameters,
+        )?;
         Feature::new_super_sampling(
             self.device,
             command_buffer,
             feature_parameters,
             create_parameters,
         )
+        .map(|mut feature| {
+            feature.get_inner_mut().set_scratch_buffer(scratch_buffer);
+            feature
+        })
     }
 
     /// Creates a frame generation feature.
@@ -179,7 +283,16 @@ impl System {
         command_buffer: vk::CommandBuffer,
         feature_parameters: FeatureParameters,
     ) -> Result<Feature> {
-        Feature::new_frame_generation(self.device, command_buffer, feature_parameters)
+        let scratch_buffer = self.provision_scratch_buffer(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration,
+            &feature_parameters,
+        )?;
+        Feature::new_frame_generation(self.device, command_buffer, feature_parameters).map(
+            |mut feature| {
+                feature.set_scratch_buffer(scratch_buffer);
+                feature
+            },
+        )
     }
 
     /// Creates a ray reconstruction feature.
@@ -189,12 +302,20 @@ impl System {
         feature_parameters: FeatureParameters,
         create_parameters: RayReconstructionCreateParameters,
     ) -> Result<RayReconstructionFeature> {
+        let scratch_buffer = self.provision_scratch_buffer(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction,
+            &feature_parameters,
+        )?;
         Feature::new_ray_reconstruction(
             self.device,
             command_buffer,
             feature_parameters,
             create_parameters,
         )
+        .map(|mut feature| {
+            feature.get_inner_mut().set_scratch_buffer(scratch_buffer);
+            feature
+        })
     }
 }
 
@@ -255,6 +376,54 @@ impl VkImageResourceDescription {
     }
 }
 
+impl From<VkBufferResourceDescription> for NVSDK_NGX_Resource_VK {
+    fn from(value: VkBufferResourceDescription) -> Self {
+        let buffer_info = NVSDK_NGX_BufferInfo_VK {
+            Buffer: value.buffer,
+            SizeInBytes: value.size_in_bytes as _,
+        };
+
+        // See the comment in the `VkImageResourceDescription`
+        // conversion.
+        let mut buffer_resource = NVSDK_NGX_Resource_VK__bindgen_ty_1::default();
+        unsafe { *buffer_resource.BufferInfo.as_mut() = buffer_info }
+
+        Self {
+            Resource: buffer_resource,
+            Type: NVSDK_NGX_Resource_VK_Type::NVSDK_NGX_RESOURCE_VK_TYPE_VK_BUFFER,
+            ReadWrite: matches!(value.mode, VkResourceMode::Writable),
+        }
+    }
+}
+
+/// Checks that the subrect starting at `base` and of the `size`
+/// fits into the image `resource`. The check is skipped when the
+/// resource isn't set (is null) or isn't an image.
+pub(crate) fn check_subrect_fits(
+    name: &str,
+    resource: *const NVSDK_NGX_Resource_VK,
+    base: NVSDK_NGX_Coordinates,
+    size: vk::Extent2D,
+) -> Result {
+    let Some(resource) = (unsafe { resource.as_ref() }) else {
+        return Ok(());
+    };
+    if resource.Type != NVSDK_NGX_Resource_VK_Type::NVSDK_NGX_RESOURCE_VK_TYPE_VK_IMAGEVIEW {
+        return Ok(());
+    }
+    let image_view_info = unsafe { resource.Resource.ImageViewInfo.as_ref() };
+    let fits_width = base.X as u64 + size.width as u64 <= image_view_info.Width as u64;
+    let fits_height = base.Y as u64 + size.height as u64 <= image_view_info.Height as u64;
+    if fits_width && fits_height {
+        Ok(())
+    } else {
+        Err(nvngx_sys::Error::Other(format!(
+            "The {name} subrect at [{}, {}] of size [{}, {}] doesn't fit into the resource of size [{}, {}].",
+            base.X, base.Y, size.width, size.height, image_view_info.Width, image_view_info.Height
+        )))
+    }
+}
+
 impl From<VkImageResourceDescription> for NVSDK_NGX_Resource_VK {
     fn from(value: VkImageResourceDescription) -> Self {
         let vk_image_subresource_range = vk::ImageSubresourceRange {
@@ -0,0 +1,168 @@
+//! The conventions the motion vectors may be produced in.
+
+use super::*;
+
+/// The space (the units) the motion vectors are stored in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MotionVectorSpace {
+    /// In pixels, the way the NGX expects them.
+    Pixels,
+    /// In the normalised device coordinates, where the whole image
+    /// spans the range of `[-1, 1]`.
+    Ndc,
+    /// In the texture coordinates, where the whole image spans the
+    /// range of `[0, 1]`.
+    Uv,
+}
+
+/// The direction the Y axis of the motion vectors points to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MotionVectorYDirection {
+    /// The Y axis points down, as the pixel rows and the Vulkan
+    /// normalised device coordinates do. This is what the NGX expects.
+    Down,
+    /// The Y axis points up, as in the OpenGL or Direct3D normalised
+    /// device coordinates.
+    Up,
+}
+
+/// The resolution the motion vectors are rendered in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MotionVectorResolution {
+    /// The render (input) resolution, corresponding to the
+    /// [`DlssFeatureFlags::mv_low_res`] flag.
+    Render,
+    /// The target (output) resolution.
+    Output,
+}
+
+/// Describes how the motion vectors are produced by the renderer, so
+/// that the motion vectors scale
+/// ([`TemporalUpscalerInputs::set_motions_vectors`]) and the
+/// creation flags ([`DlssFeatureFlags::mv_jittered`] and
+/// [`DlssFeatureFlags::mv_low_res`]) are derived from one place and
+/// stay consistent with each other.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MotionVectorConvention {
+    /// The space the motion vectors are stored in.
+    pub space: MotionVectorSpace,
+    /// The direction of the Y axis.
+    pub y_direction: MotionVectorYDirection,
+    /// Whether the motion vectors include the jitter of the camera
+    /// (were computed with the jittered projection matrices).
+    pub includes_jitter: bool,
+    /// The resolution the motion vectors are rendered in.
+    pub resolution: MotionVectorResolution,
+}
+
+impl MotionVectorConvention {
+    /// Creates a new motion vectors convention.
+    pub const fn new(
+        space: MotionVectorSpace,
+        y_direction: MotionVectorYDirection,
+        includes_jitter: bool,
+        resolution: MotionVectorResolution,
+    ) -> Self {
+        Self {
+            space,
+            y_direction,
+            includes_jitter,
+            resolution,
+        }
+    }
+
+    /// Returns the `flags` with the [`DlssFeatureFlags::mv_jittered`]
+    /// and [`DlssFeatureFlags::mv_low_res`] flags set according to the
+    /// convention.
+    pub const fn apply_to_flags(&self, flags: DlssFeatureFlags) -> DlssFeatureFlags {
+        flags
+            .mv_jittered(self.includes_jitter)
+            .mv_low_res(matches!(self.resolution, MotionVectorResolution::Render))
+    }
+
+    /// Returns an error if the `flags` a feature was created with
+    /// don't match the convention.
+    pub fn check_flags(&self, flags: DlssFeatureFlags) -> Result {
+        if self.apply_to_flags(flags) == flags {
+            Ok(())
+        } else {
+            Err(nvngx_sys::Error::Other(format!(
+                "The feature flags {flags:?} don't match the motion vectors convention {self:?}."
+            )))
+        }
+    }
+
+    /// Returns the motion vectors scale (`InMVScaleX` and
+    /// `InMVScaleY`) converting the motion vectors to the pixels of
+    /// the resolution they are rendered in: the `render_size` or the
+    /// `target_size`.
+    pub fn get_scale(&self, render_size: vk::Extent2D, target_size: vk::Extent2D) -> [f32; 2] {
+        let size = match self.resolution {
+            MotionVectorResolution::Render => render_size,
+            MotionVectorResolution::Output => target_size,
+        };
+        let [x, y] = match self.space {
+            MotionVectorSpace::Pixels => [1.0, 1.0],
+            MotionVectorSpace::Ndc => [size.width as f32 * 0.5, size.height as f32 * 0.5],
+            MotionVectorSpace::Uv => [size.width as f32, size.height as f32],
+        };
+        match self.y_direction {
+            MotionVectorYDirection::Down => [x, y],
+            MotionVectorYDirection::Up => [x, -y],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_from_convention() {
+        let render_size = vk::Extent2D::default().width(960).height(540);
+        let target_size = vk::Extent2D::default().width(1920).height(1080);
+        let convention = |space, y_direction, resolution| {
+            MotionVectorConvention::new(space, y_direction, false, resolution)
+                .get_scale(render_size, target_size)
+        };
+
+        assert_eq!(
+            convention(
+                MotionVectorSpace::Pixels,
+                MotionVectorYDirection::Down,
+                MotionVectorResolution::Render
+            ),
+            [1.0, 1.0]
+        );
+        assert_eq!(
+            convention(
+                MotionVectorSpace::Ndc,
+                MotionVectorYDirection::Up,
+                MotionVectorResolution::Render
+            ),
+            [480.0, -270.0]
+        );
+        assert_eq!(
+            convention(
+                MotionVectorSpace::Uv,
+                MotionVectorYDirection::Down,
+                MotionVectorResolution::Output
+            ),
+            [1920.0, 1080.0]
+        );
+    }
+
+    #[test]
+    fn flags_from_convention() {
+        let convention = MotionVectorConvention::new(
+            MotionVectorSpace::Uv,
+            MotionVectorYDirection::Down,
+            true,
+            MotionVectorResolution::Render,
+        );
+        let flags = convention.apply_to_flags(DlssFeatureFlags::new().hdr(true));
+        assert!(flags.is_hdr() && flags.is_mv_jittered() && flags.is_mv_low_res());
+        assert!(convention.check_flags(flags).is_ok());
+        assert!(convention.check_flags(DlssFeatureFlags::new()).is_err());
+    }
+}
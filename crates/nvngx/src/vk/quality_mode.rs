@@ -0,0 +1,188 @@
+//! The quality modes of the DLSS features.
+
+use nvngx_sys::NVSDK_NGX_PerfQuality_Value;
+
+/// The quality mode (the "performance/quality value") of the DLSS
+/// features, named the way they are presented to the users.
+///
+/// Converts to and from the [`NVSDK_NGX_PerfQuality_Value`], which
+/// names some of the modes differently: [`Self::Performance`] is the
+/// [`NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf`]
+/// and [`Self::Quality`] is the
+/// [`NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxQuality`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum QualityMode {
+    /// The fastest mode, rendering at a third of the target
+    /// resolution.
+    UltraPerformance,
+    /// Renders at a half of the target resolution.
+    Performance,
+    /// Renders at roughly 58% of the target resolution.
+    Balanced,
+    /// Renders at two thirds of the target resolution.
+    Quality,
+    /// Renders at roughly 77% of the target resolution.
+    UltraQuality,
+    /// Deep Learning Anti-Aliasing: renders at the target resolution,
+    /// only anti-aliasing the image.
+    Dlaa,
+}
+
+impl QualityMode {
+    /// All the quality modes, from the fastest to the highest quality.
+    pub const ALL: [Self; 6] = [
+        Self::UltraPerformance,
+        Self::Performance,
+        Self::Balanced,
+        Self::Quality,
+        Self::UltraQuality,
+        Self::Dlaa,
+    ];
+
+    /// Returns the name of the quality mode as presented to the users.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::UltraPerformance => "Ultra Performance",
+            Self::Performance => "Performance",
+            Self::Balanced => "Balanced",
+            Self::Quality => "Quality",
+            Self::UltraQuality => "Ultra Quality",
+            Self::Dlaa => "DLAA",
+        }
+    }
+
+    /// Returns the nominal ratio of the render resolution to the target
+    /// resolution (per axis), for example, `0.5` for
+    /// [`Self::Performance`].
+    ///
+    /// The actual render resolution is returned by
+    /// [`SuperSamplingOptimalSettings::get_optimal_settings`](crate::SuperSamplingOptimalSettings::get_optimal_settings)
+    /// and may slightly differ.
+    pub const fn get_render_scale(self) -> f32 {
+        match self {
+            Self::UltraPerformance => 1.0 / 3.0,
+            Self::Performance => 0.5,
+            Self::Balanced => 0.58,
+            Self::Quality => 2.0 / 3.0,
+            Self::UltraQuality => 0.77,
+            Self::Dlaa => 1.0,
+        }
+    }
+
+    /// Returns the nominal scale factor: how many times the target
+    /// resolution is bigger than the render resolution (per axis), for
+    /// example, `2.0` for [`Self::Performance`].
+    pub fn get_scale_factor(self) -> f32 {
+        1.0 / self.get_render_scale()
+    }
+}
+
+impl std::fmt::Display for QualityMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for QualityMode {
+    type Err = nvngx_sys::Error;
+
+    /// Parses the quality mode case-insensitively, ignoring the
+    /// spaces, dashes and underscores, so that `"Ultra Performance"`,
+    /// `"ultra_performance"` and `"UltraPerformance"` are all accepted.
+    /// The NGX names (`"MaxPerf"` and `"MaxQuality"`) are accepted too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalised: String = s
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        Ok(match normalised.as_str() {
+            "ultraperformance" => Self::UltraPerformance,
+            "performance" | "maxperf" => Self::Performance,
+            "balanced" => Self::Balanced,
+            "quality" | "maxquality" => Self::Quality,
+            "ultraquality" => Self::UltraQuality,
+            "dlaa" => Self::Dlaa,
+            _ => {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "Unknown quality mode: {s:?}"
+                )))
+            }
+        })
+    }
+}
+
+impl From<QualityMode> for NVSDK_NGX_PerfQuality_Value {
+    fn from(value: QualityMode) -> Self {
+        match value {
+            QualityMode::UltraPerformance => Self::NVSDK_NGX_PerfQuality_Value_UltraPerformance,
+            QualityMode::Performance => Self::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+            QualityMode::Balanced => Self::NVSDK_NGX_PerfQuality_Value_Balanced,
+            QualityMode::Quality => Self::NVSDK_NGX_PerfQuality_Value_MaxQuality,
+            QualityMode::UltraQuality => Self::NVSDK_NGX_PerfQuality_Value_UltraQuality,
+            QualityMode::Dlaa => Self::NVSDK_NGX_PerfQuality_Value_DLAA,
+        }
+    }
+}
+
+impl TryFrom<NVSDK_NGX_PerfQuality_Value> for QualityMode {
+    type Error = nvngx_sys::Error;
+
+    fn try_from(value: NVSDK_NGX_PerfQuality_Value) -> Result<Self, Self::Error> {
+        Ok(match value {
+            NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_UltraPerformance => {
+                Self::UltraPerformance
+            }
+            NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf => Self::Performance,
+            NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_Balanced => Self::Balanced,
+            NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxQuality => Self::Quality,
+            NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_UltraQuality => {
+                Self::UltraQuality
+            }
+            NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_DLAA => Self::Dlaa,
+            value => {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "Unknown quality value: {value:?}"
+                )))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QualityMode;
+
+    #[test]
+    fn display_and_parse_round_trip() {
+        for mode in QualityMode::ALL {
+            assert_eq!(mode.to_string().parse::<QualityMode>().unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn parse_alternative_names() {
+        assert_eq!(
+            "ultra_performance".parse::<QualityMode>().unwrap(),
+            QualityMode::UltraPerformance
+        );
+        assert_eq!(
+            "MaxPerf".parse::<QualityMode>().unwrap(),
+            QualityMode::Performance
+        );
+        assert_eq!(
+            "max-quality".parse::<QualityMode>().unwrap(),
+            QualityMode::Quality
+        );
+        assert!("ultra".parse::<QualityMode>().is_err());
+    }
+
+    #[test]
+    fn sys_conversion_round_trip() {
+        for mode in QualityMode::ALL {
+            let value = nvngx_sys::NVSDK_NGX_PerfQuality_Value::from(mode);
+            assert_eq!(QualityMode::try_from(value).unwrap(), mode);
+        }
+    }
+}
@@ -7,18 +7,247 @@ use nvngx_sys::{
 
 use super::*;
 
-impl From<SuperSamplingOptimalSettings> for RayReconstructionCreateParameters {
-    fn from(value: SuperSamplingOptimalSettings) -> Self {
-        Self::new(
-            value.render_width,
-            value.render_height,
-            value.target_width,
-            value.target_height,
-            Some(value.desired_quality_level),
-            None,
-            None,
-            None,
+/// Optimal settings for the Ray Reconstruction (DLSS-RR) based on the
+/// desired quality level and resolution.
+///
+/// The Ray Reconstruction may recommend the render resolutions
+/// different from the ones of the SuperSampling
+/// ([`SuperSamplingOptimalSettings::get_optimal_settings`]) for the
+/// same quality level.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RayReconstructionOptimalSettings {
+    /// The render and target resolutions, the quality level and the
+    /// dynamic resolution bounds, in the same form the SuperSampling
+    /// ones are.
+    pub settings: SuperSamplingOptimalSettings,
+}
+
+impl RayReconstructionOptimalSettings {
+    /// Returns a set of optimal settings for the desired parameter
+    /// set, render dimensions and quality level, using the
+    /// [`nvngx_sys::HELPERS_NGX_DLSSD_GET_OPTIMAL_SETTINGS`].
+    pub fn get_optimal_settings(
+        parameters: &FeatureParameters,
+        target_width: u32,
+        target_height: u32,
+        desired_quality_level: QualityMode,
+    ) -> Result<Self> {
+        SuperSamplingOptimalSettings::get_supported_optimal_settings(
+            nvngx_sys::HELPERS_NGX_DLSSD_GET_OPTIMAL_SETTINGS,
+            parameters,
+            target_width,
+            target_height,
+            desired_quality_level,
         )
+        .map(|settings| Self { settings })
+    }
+}
+
+impl From<RayReconstructionOptimalSettings> for RayReconstructionCreateParameters {
+    /// Uses no flags and the default [`DepthType`], see
+    /// [`RayReconstructionCreateParameters::from_optimal_settings`] to
+    /// set them.
+    fn from(value: RayReconstructionOptimalSettings) -> Self {
+        Self::from_optimal_settings(value, DlssFeatureFlags::new(), DepthType::default())
+    }
+}
+
+/// Video memory statistics of the Ray Reconstruction feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RayReconstructionStats {
+    /// The amount of video memory (in bytes) allocated by the Ray
+    /// Reconstruction features.
+    pub vram_allocated_bytes: u64,
+}
+
+impl RayReconstructionStats {
+    /// Returns the video memory statistics of the Ray Reconstruction
+    /// feature, using the
+    /// [`nvngx_sys::NVSDK_NGX_Parameter_DLSSDGetStatsCallback`].
+    ///
+    /// The `parameters` must be obtained via
+    /// [`FeatureParameters::get_capability_parameters`]. The value
+    /// reported is the memory currently allocated by the created Ray
+    /// Reconstruction features, see
+    /// [`RayReconstructionVramEstimate::get_vram_estimate`] for an
+    /// estimate before the creation.
+    pub fn get_stats(parameters: &FeatureParameters) -> Result<Self> {
+        parameters
+            .get_allocated_vram_bytes(nvngx_sys::NVSDK_NGX_Parameter_DLSSDGetStatsCallback)
+            .map(|vram_allocated_bytes| Self {
+                vram_allocated_bytes,
+            })
+    }
+}
+
+/// An estimate of the video memory the Ray Reconstruction feature
+/// requires for the given resolutions and quality level.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RayReconstructionVramEstimate {
+    /// The render resolution.
+    pub render_size: vk::Extent2D,
+    /// The target resolution.
+    pub target_size: vk::Extent2D,
+    /// The quality level.
+    pub quality_level: QualityMode,
+    /// The estimated amount of video memory in bytes.
+    pub estimated_vram_bytes: u64,
+}
+
+impl RayReconstructionVramEstimate {
+    /// Returns an estimate of the video memory a Ray Reconstruction
+    /// feature of the resolutions, the quality level and the `flags`
+    /// requires, the same way
+    /// [`SuperSamplingVramEstimate::get_vram_estimate`] does, including
+    /// its requirements for the `command_buffer`.
+    pub fn get_vram_estimate(
+        system: &System,
+        command_buffer: vk::CommandBuffer,
+        render_size: vk::Extent2D,
+        target_size: vk::Extent2D,
+        quality_level: QualityMode,
+        flags: DlssFeatureFlags,
+    ) -> Result<Self> {
+        let estimated_vram_bytes = system.measure_feature_vram_bytes(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction,
+            nvngx_sys::NVSDK_NGX_Parameter_DLSSDGetStatsCallback,
+            |feature_parameters| {
+                system.create_ray_reconstruction_feature(
+                    command_buffer,
+                    feature_parameters,
+                    RayReconstructionCreateParameters::new(
+                        render_size.width,
+                        render_size.height,
+                        target_size.width,
+                        target_size.height,
+                        Some(quality_level),
+                        None,
+                        None,
+                        DepthType::default(),
+                        flags,
+                    ),
+                )
+            },
+        )?;
+
+        Ok(Self {
+            render_size,
+            target_size,
+            quality_level,
+            estimated_vram_bytes,
+        })
+    }
+}
+
+/// The denoising the Ray Reconstruction performs.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DenoiseMode {
+    /// No denoising.
+    Off,
+    /// The denoising unified with the upscaling in one neural network.
+    /// This is the default.
+    #[default]
+    DlUnified,
+}
+
+impl From<DenoiseMode> for NVSDK_NGX_DLSS_Denoise_Mode {
+    fn from(value: DenoiseMode) -> Self {
+        match value {
+            DenoiseMode::Off => Self::NVSDK_NGX_DLSS_Denoise_Mode_Off,
+            DenoiseMode::DlUnified => Self::NVSDK_NGX_DLSS_Denoise_Mode_DLUnified,
+        }
+    }
+}
+
+impl TryFrom<NVSDK_NGX_DLSS_Denoise_Mode> for DenoiseMode {
+    type Error = nvngx_sys::Error;
+
+    fn try_from(value: NVSDK_NGX_DLSS_Denoise_Mode) -> Result<Self, Self::Error> {
+        Ok(match value {
+            NVSDK_NGX_DLSS_Denoise_Mode::NVSDK_NGX_DLSS_Denoise_Mode_Off => Self::Off,
+            NVSDK_NGX_DLSS_Denoise_Mode::NVSDK_NGX_DLSS_Denoise_Mode_DLUnified => Self::DlUnified,
+            value => {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "Unknown denoise mode: {value:?}"
+                )))
+            }
+        })
+    }
+}
+
+/// The kind of the depth the Ray Reconstruction is given
+/// ([`TemporalUpscalerInputs::set_depth_buffer`]).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DepthType {
+    /// The linear depth: the view-space distance along the view
+    /// direction. This is the default.
+    #[default]
+    Linear,
+    /// The hardware depth: the non-linear depth buffer written by the
+    /// rasterisation, in the normalised device coordinates.
+    Hardware,
+}
+
+impl From<DepthType> for NVSDK_NGX_DLSS_Depth_Type {
+    fn from(value: DepthType) -> Self {
+        match value {
+            DepthType::Linear => Self::NVSDK_NGX_DLSS_Depth_Type_Linear,
+            DepthType::Hardware => Self::NVSDK_NGX_DLSS_Depth_Type_HW,
+        }
+    }
+}
+
+impl TryFrom<NVSDK_NGX_DLSS_Depth_Type> for DepthType {
+    type Error = nvngx_sys::Error;
+
+    fn try_from(value: NVSDK_NGX_DLSS_Depth_Type) -> Result<Self, Self::Error> {
+        Ok(match value {
+            NVSDK_NGX_DLSS_Depth_Type::NVSDK_NGX_DLSS_Depth_Type_Linear => Self::Linear,
+            NVSDK_NGX_DLSS_Depth_Type::NVSDK_NGX_DLSS_Depth_Type_HW => Self::Hardware,
+            value => {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "Unknown depth type: {value:?}"
+                )))
+            }
+        })
+    }
+}
+
+/// How the Ray Reconstruction is given the roughness of the surfaces.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RoughnessMode {
+    /// The roughness is a separate input
+    /// ([`RayReconstructionEvaluationParameters::set_roughness`]). This
+    /// is the default.
+    #[default]
+    Unpacked,
+    /// The roughness is packed into the alpha channel of the normals
+    /// ([`RayReconstructionEvaluationParameters::set_normals`]).
+    Packed,
+}
+
+impl From<RoughnessMode> for NVSDK_NGX_DLSS_Roughness_Mode {
+    fn from(value: RoughnessMode) -> Self {
+        match value {
+            RoughnessMode::Unpacked => Self::NVSDK_NGX_DLSS_Roughness_Mode_Unpacked,
+            RoughnessMode::Packed => Self::NVSDK_NGX_DLSS_Roughness_Mode_Packed,
+        }
+    }
+}
+
+impl TryFrom<NVSDK_NGX_DLSS_Roughness_Mode> for RoughnessMode {
+    type Error = nvngx_sys::Error;
+
+    fn try_from(value: NVSDK_NGX_DLSS_Roughness_Mode) -> Result<Self, Self::Error> {
+        Ok(match value {
+            NVSDK_NGX_DLSS_Roughness_Mode::NVSDK_NGX_DLSS_Roughness_Mode_Unpacked => Self::Unpacked,
+            NVSDK_NGX_DLSS_Roughness_Mode::NVSDK_NGX_DLSS_Roughness_Mode_Packed => Self::Packed,
+            value => {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "Unknown roughness mode: {value:?}"
+                )))
+            }
+        })
     }
 }
 
@@ -28,18 +257,28 @@ impl From<SuperSamplingOptimalSettings> for RayReconstructionCreateParameters {
 pub struct RayReconstructionCreateParameters(pub(crate) nvngx_sys::NVSDK_NGX_DLSSD_Create_Params);
 
 impl RayReconstructionCreateParameters {
-    /// Creates a new set of create parameters for the SuperSampling
-    /// feature.
+    /// Creates a new set of create parameters for the Ray
+    /// Reconstruction feature.
+    ///
+    /// The `flags` are the same as the SuperSampling ones, describing
+    /// the color, the depth and the motion vectors. The
+    /// SuperSampling-only flags (see [`Self::check_flags`]) fail the
+    /// creation of the feature. The `depth_type`
+    /// tells whether the depth is linear or the hardware one. Without
+    /// the `denoise_mode` and the `roughness_mode`, the
+    /// [`DenoiseMode::default`] and the [`RoughnessMode::default`] are
+    /// used.
     #[allow(clippy::too_many_arguments)] // Struct constructor
     pub fn new(
         render_width: u32,
         render_height: u32,
         target_width: u32,
         target_height: u32,
-        quality_value: Option<NVSDK_NGX_PerfQuality_Value>,
-        denoise_mode: Option<NVSDK_NGX_DLSS_Denoise_Mode>,
-        roughness_mode: Option<NVSDK_NGX_DLSS_Roughness_Mode>,
-        depth_type: Option<NVSDK_NGX_DLSS_Depth_Type>,
+        quality_value: Option<QualityMode>,
+        denoise_mode: Option<DenoiseMode>,
+        roughness_mode: Option<RoughnessMode>,
+        depth_type: DepthType,
+        flags: DlssFeatureFlags,
     ) -> Self {
         Self(NVSDK_NGX_DLSSD_Create_Params {
             InWidth: render_width,
@@ -47,18 +286,246 @@ impl RayReconstructionCreateParameters {
             InTargetWidth: target_width,
             InTargetHeight: target_height,
             // Equivalent to 0
-            InPerfQualityValue: quality_value
-                .unwrap_or(NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf),
-            InDenoiseMode: denoise_mode
-                .unwrap_or(NVSDK_NGX_DLSS_Denoise_Mode::NVSDK_NGX_DLSS_Denoise_Mode_DLUnified),
-            InRoughnessMode: roughness_mode
-                .unwrap_or(NVSDK_NGX_DLSS_Roughness_Mode::NVSDK_NGX_DLSS_Roughness_Mode_Unpacked),
-            InUseHWDepth: depth_type
-                .unwrap_or(NVSDK_NGX_DLSS_Depth_Type::NVSDK_NGX_DLSS_Depth_Type_Linear),
-            InFeatureCreateFlags: 0,
+            InPerfQualityValue: quality_value.unwrap_or(QualityMode::Performance).into(),
+            InDenoiseMode: denoise_mode.unwrap_or_default().into(),
+            InRoughnessMode: roughness_mode.unwrap_or_default().into(),
+            InUseHWDepth: depth_type.into(),
+            InFeatureCreateFlags: flags.0,
             InEnableOutputSubrects: false,
         })
     }
+
+    /// Creates a new set of create parameters for the Ray
+    /// Reconstruction feature from the optimal settings, the `flags`
+    /// and the `depth_type` provided, with the default denoise and
+    /// roughness modes.
+    pub fn from_optimal_settings(
+        settings: RayReconstructionOptimalSettings,
+        flags: DlssFeatureFlags,
+        depth_type: DepthType,
+    ) -> Self {
+        let settings = settings.settings;
+        Self::new(
+            settings.render_width,
+            settings.render_height,
+            settings.target_width,
+            settings.target_height,
+            Some(settings.desired_quality_level),
+            None,
+            None,
+            depth_type,
+            flags,
+        )
+    }
+
+    /// Returns the feature creation flags.
+    pub fn get_flags(&self) -> DlssFeatureFlags {
+        DlssFeatureFlags(self.0.InFeatureCreateFlags)
+    }
+
+    /// Fails if any of the flags only the SuperSampling supports is
+    /// set: the sharpening ([`DlssFeatureFlags::do_sharpening`]) or
+    /// the alpha upscaling ([`DlssFeatureFlags::alpha_upscaling`]; the
+    /// Ray Reconstruction takes the separate alpha input instead, see
+    /// [`RayReconstructionEvaluationParameters::set_alpha_input`]).
+    pub fn check_flags(&self) -> Result {
+        let flags = self.get_flags();
+        for (name, is_set) in [
+            ("sharpening", flags.is_sharpening()),
+            ("alpha upscaling", flags.is_alpha_upscaling()),
+        ] {
+            if is_set {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "The Ray Reconstruction doesn't support the {name} flag."
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the denoise mode, or an error if the raw value is
+    /// unknown.
+    pub fn get_denoise_mode(&self) -> Result<DenoiseMode> {
+        DenoiseMode::try_from(self.0.InDenoiseMode)
+    }
+
+    /// Returns the kind of the depth, or an error if the raw value is
+    /// unknown.
+    pub fn get_depth_type(&self) -> Result<DepthType> {
+        DepthType::try_from(self.0.InUseHWDepth)
+    }
+
+    /// Returns the roughness mode, or an error if the raw value is
+    /// unknown.
+    pub fn get_roughness_mode(&self) -> Result<RoughnessMode> {
+        RoughnessMode::try_from(self.0.InRoughnessMode)
+    }
+
+    /// Enables or disables the output subrects, so that the
+    /// reconstructed image may be written to a region of a bigger
+    /// output resource.
+    pub fn with_output_subrects(mut self, enabled: bool) -> Self {
+        self.0.InEnableOutputSubrects = enabled;
+        self
+    }
+
+    /// Returns `true` if the output subrects are enabled.
+    pub fn is_output_subrects_enabled(&self) -> bool {
+        self.0.InEnableOutputSubrects
+    }
+}
+
+/// The lobe of the ray traced lighting the ray guides describe.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RayGuideLobe {
+    /// The diffuse lighting.
+    Diffuse,
+    /// The specular lighting.
+    Specular,
+}
+
+/// An optional image input of the Ray Reconstruction, like a ray guide
+/// or a composition layer: the image and the offset of the rendered
+/// region in it.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SubrectInput {
+    /// The image.
+    pub description: VkImageResourceDescription,
+    /// The offset of the rendered region in the image.
+    pub subrect_base: [u32; 2],
+}
+
+impl From<VkImageResourceDescription> for SubrectInput {
+    fn from(description: VkImageResourceDescription) -> Self {
+        Self {
+            description,
+            subrect_base: [0, 0],
+        }
+    }
+}
+
+/// Stores the `input` in the `resource` and points the `pointer` to it,
+/// or unsets the `pointer` if there is no `input`.
+fn set_subrect_input(
+    input: Option<SubrectInput>,
+    resource: &mut NVSDK_NGX_Resource_VK,
+    pointer: &mut *mut NVSDK_NGX_Resource_VK,
+    base: &mut NVSDK_NGX_Coordinates,
+) {
+    match input {
+        Some(input) => {
+            *resource = input.description.into();
+            *pointer = resource;
+            *base = NVSDK_NGX_Coordinates {
+                X: input.subrect_base[0],
+                Y: input.subrect_base[1],
+            };
+        }
+        None => {
+            *pointer = std::ptr::null_mut();
+            *base = NVSDK_NGX_Coordinates { X: 0, Y: 0 };
+        }
+    }
+}
+
+/// The optional ray guides of a lobe ([`RayGuideLobe`]), improving the
+/// quality of the Ray Reconstruction: either the hit distance and the
+/// ray direction separately, or both combined in one image (the ray
+/// direction in the RGB channels and the hit distance in the alpha
+/// channel), but not both ways at once.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct RayGuideInputs {
+    /// The distance from the primary surfaces to the ray hits.
+    pub hit_distance: Option<SubrectInput>,
+    /// The direction of the rays.
+    pub ray_direction: Option<SubrectInput>,
+    /// The direction of the rays and the hit distance combined.
+    pub ray_direction_hit_distance: Option<SubrectInput>,
+}
+
+impl RayGuideInputs {
+    /// Fails if both the combined and any of the separate guides are
+    /// set.
+    pub fn validate(&self) -> Result {
+        if self.ray_direction_hit_distance.is_some()
+            && (self.hit_distance.is_some() || self.ray_direction.is_some())
+        {
+            return Err(nvngx_sys::Error::Other(
+                "The combined ray direction and hit distance guide can't be used together with the separate hit distance or ray direction guides.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A pass the renderer applies to the image after the Ray
+/// Reconstruction inputs are rendered, which the Ray Reconstruction can
+/// account for when given the color before and after the pass
+/// ([`CompositionLayerInputs`]).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionLayer {
+    /// The particles.
+    Particles,
+    /// The transparent objects.
+    Transparency,
+    /// The fog.
+    Fog,
+    /// The screen-space subsurface scattering, accepting a guide.
+    ScreenSpaceSubsurfaceScattering,
+    /// The screen-space refraction, accepting a guide.
+    ScreenSpaceRefraction,
+    /// The depth of field, accepting a guide.
+    DepthOfField,
+}
+
+impl CompositionLayer {
+    /// Returns [`true`] if the layer accepts a guide
+    /// ([`CompositionLayerInputs::guide`]).
+    pub const fn has_guide(self) -> bool {
+        matches!(
+            self,
+            Self::ScreenSpaceSubsurfaceScattering
+                | Self::ScreenSpaceRefraction
+                | Self::DepthOfField
+        )
+    }
+}
+
+/// The inputs of a [`CompositionLayer`], each at its own offset.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct CompositionLayerInputs {
+    /// The color before the pass.
+    pub color_before: Option<SubrectInput>,
+    /// The color after the pass.
+    pub color_after: Option<SubrectInput>,
+    /// The guide of the pass, only accepted by some of the layers
+    /// ([`CompositionLayer::has_guide`]).
+    pub guide: Option<SubrectInput>,
+}
+
+impl CompositionLayerInputs {
+    /// Fails if only one of the colors is set, or if the guide is set
+    /// without the colors or for a `layer` which doesn't accept it.
+    pub fn validate(&self, layer: CompositionLayer) -> Result {
+        if self.color_before.is_some() != self.color_after.is_some() {
+            return Err(nvngx_sys::Error::Other(format!(
+                "Both the color before and after the {layer:?} composition layer must be set."
+            )));
+        }
+        if self.guide.is_some() {
+            if !layer.has_guide() {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "The {layer:?} composition layer doesn't accept a guide."
+                )));
+            }
+            if self.color_before.is_none() {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "The guide of the {layer:?} composition layer requires the colors before and after it."
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// The Ray Reconstruction evaluation parameters.
@@ -76,6 +543,37 @@ pub struct RayReconstructionEvaluationParameters {
     pub(crate) depth_resource: NVSDK_NGX_Resource_VK,
     /// The motion vectors.
     pub(crate) motion_vectors_resource: NVSDK_NGX_Resource_VK,
+    /// The alpha of the color input.
+    pub(crate) input_alpha_resource: NVSDK_NGX_Resource_VK,
+    /// The alpha of the color output.
+    pub(crate) output_alpha_resource: NVSDK_NGX_Resource_VK,
+    /// The diffuse albedo.
+    pub(crate) diffuse_albedo_resource: NVSDK_NGX_Resource_VK,
+    /// The specular albedo.
+    pub(crate) specular_albedo_resource: NVSDK_NGX_Resource_VK,
+    /// The normals.
+    pub(crate) normals_resource: NVSDK_NGX_Resource_VK,
+    /// The roughness.
+    pub(crate) roughness_resource: NVSDK_NGX_Resource_VK,
+    /// The world to view (camera) matrix.
+    pub(crate) world_to_view_matrix: CameraMatrix,
+    /// The view to clip (projection) matrix.
+    pub(crate) view_to_clip_matrix: CameraMatrix,
+    /// The diffuse hit distance.
+    pub(crate) diffuse_hit_distance_resource: NVSDK_NGX_Resource_VK,
+    /// The specular hit distance.
+    pub(crate) specular_hit_distance_resource: NVSDK_NGX_Resource_VK,
+    /// The diffuse ray direction.
+    pub(crate) diffuse_ray_direction_resource: NVSDK_NGX_Resource_VK,
+    /// The specular ray direction.
+    pub(crate) specular_ray_direction_resource: NVSDK_NGX_Resource_VK,
+    /// The diffuse ray direction and hit distance.
+    pub(crate) diffuse_ray_direction_hit_distance_resource: NVSDK_NGX_Resource_VK,
+    /// The specular ray direction and hit distance.
+    pub(crate) specular_ray_direction_hit_distance_resource: NVSDK_NGX_Resource_VK,
+    /// The color before, the color after and the guide of each of the
+    /// composition layers, indexed by the [`CompositionLayer`].
+    pub(crate) composition_layer_resources: [[NVSDK_NGX_Resource_VK; 3]; 6],
 
     /// This member isn't visible, as it shouldn't be managed by
     /// the user of this struct. Instead, this struct provides an
@@ -96,85 +594,320 @@ impl RayReconstructionEvaluationParameters {
         Self::default()
     }
 
-    /// Sets the color input parameter (the image to upscale).
-    pub fn set_color_input(&mut self, description: VkImageResourceDescription) {
-        self.input_color_resource = description.into();
-        self.parameters.pInColor = std::ptr::addr_of_mut!(self.input_color_resource);
+    /// Sets the alpha of the color input, in a separate single-channel
+    /// image (`R8_UNORM`, `R16_UNORM`, `R16_SFLOAT` or `R32_SFLOAT`).
+    /// Requires the alpha output ([`Self::set_alpha_output`]) to be set
+    /// as well.
+    pub fn set_alpha_input(&mut self, description: VkImageResourceDescription) {
+        self.input_alpha_resource = description.into();
+        self.parameters.pInAlpha = std::ptr::addr_of_mut!(self.input_alpha_resource);
     }
 
-    /// Sets the color output (the upscaled image) information.
-    pub fn set_color_output(&mut self, description: VkImageResourceDescription) {
-        self.output_color_resource = description.into();
-        self.parameters.pInOutput = std::ptr::addr_of_mut!(self.output_color_resource);
+    /// Sets the alpha output, the reconstructed alpha of the color
+    /// output, in a separate writable single-channel image. Requires
+    /// the alpha input ([`Self::set_alpha_input`]) to be set as well.
+    pub fn set_alpha_output(&mut self, description: VkImageResourceDescription) {
+        self.output_alpha_resource = description.into();
+        self.parameters.pInOutputAlpha = std::ptr::addr_of_mut!(self.output_alpha_resource);
     }
 
-    /// Sets the motion vectors.
-    /// In case the `scale` argument is omitted, the `1.0f32` scaling is
-    /// used.
-    pub fn set_motions_vectors(
-        &mut self,
-        description: VkImageResourceDescription,
-        scale: Option<[f32; 2]>,
-    ) {
-        // 1.0f32 means no scaling (they are already in the pixel space).
-        const DEFAULT_SCALING: [f32; 2] = [1.0f32, 1.0f32];
+    /// Unsets the alpha input and output, disabling the
+    /// reconstruction of the alpha.
+    pub fn unset_alpha(&mut self) {
+        self.parameters.pInAlpha = std::ptr::null_mut();
+        self.parameters.pInOutputAlpha = std::ptr::null_mut();
+    }
+
+    /// Sets the diffuse albedo of the surfaces.
+    pub fn set_diffuse_albedo(&mut self, description: VkImageResourceDescription) {
+        self.diffuse_albedo_resource = description.into();
+        self.parameters.pInDiffuseAlbedo = std::ptr::addr_of_mut!(self.diffuse_albedo_resource);
+    }
 
-        self.motion_vectors_resource = description.into();
-        let scales = scale.unwrap_or(DEFAULT_SCALING);
-        self.parameters.pInMotionVectors = std::ptr::addr_of_mut!(self.motion_vectors_resource);
-        self.parameters.InMVScaleX = scales[0];
-        self.parameters.InMVScaleY = scales[1];
+    /// Sets the specular albedo of the surfaces.
+    pub fn set_specular_albedo(&mut self, description: VkImageResourceDescription) {
+        self.specular_albedo_resource = description.into();
+        self.parameters.pInSpecularAlbedo = std::ptr::addr_of_mut!(self.specular_albedo_resource);
     }
 
-    /// Sets the depth buffer.
-    pub fn set_depth_buffer(&mut self, description: VkImageResourceDescription) {
-        self.depth_resource = description.into();
-        self.parameters.pInDepth = std::ptr::addr_of_mut!(self.depth_resource);
+    /// Sets the normals of the surfaces. With the
+    /// [`RoughnessMode::Packed`] roughness mode, the alpha channel contains the roughness, so the
+    /// format must have an alpha channel of at least 8 bits.
+    pub fn set_normals(&mut self, description: VkImageResourceDescription) {
+        self.normals_resource = description.into();
+        self.parameters.pInNormals = std::ptr::addr_of_mut!(self.normals_resource);
     }
 
-    /// Sets the jitter offsets (like TAA).
-    pub fn set_jitter_offsets(&mut self, x: f32, y: f32) {
-        self.parameters.InJitterOffsetX = x;
-        self.parameters.InJitterOffsetY = y;
+    /// Sets the roughness of the surfaces. Must not be set with the
+    /// [`RoughnessMode::Packed`] roughness mode, where the roughness is read from the normals.
+    pub fn set_roughness(&mut self, description: VkImageResourceDescription) {
+        self.roughness_resource = description.into();
+        self.parameters.pInRoughness = std::ptr::addr_of_mut!(self.roughness_resource);
     }
 
-    /// Sets/unsets the reset flag.
-    pub fn set_reset(&mut self, should_reset: bool) {
-        self.parameters.InReset = if should_reset { 1 } else { 0 };
+    /// Sets the world to view (camera) matrix of the current frame.
+    /// See [`CameraMatrix`] for how the values are passed.
+    pub fn set_world_to_view_matrix(&mut self, matrix: impl Into<CameraMatrix>) {
+        self.world_to_view_matrix = matrix.into();
+        self.parameters.pInWorldToViewMatrix = self.world_to_view_matrix.as_mut_ptr();
     }
 
-    /// Sets the rendering dimensions.
-    pub fn set_rendering_dimensions(
+    /// Sets the view to clip (projection) matrix of the current frame.
+    /// See [`CameraMatrix`] for how the values are passed.
+    ///
+    /// The matrix must not include the jitter: pass the projection
+    /// before [`apply_jitter_to_projection`], while the jitter itself
+    /// is passed via [`TemporalUpscalerInputs::set_jitter_offsets`].
+    pub fn set_view_to_clip_matrix(&mut self, matrix: impl Into<CameraMatrix>) {
+        self.view_to_clip_matrix = matrix.into();
+        self.parameters.pInViewToClipMatrix = self.view_to_clip_matrix.as_mut_ptr();
+    }
+
+    /// Sets (or, with the default [`RayGuideInputs`], unsets) the ray
+    /// guides of the `lobe`, replacing the ones previously set.
+    ///
+    /// Fails, leaving the guides intact, if both the combined and the
+    /// separate guides are provided ([`RayGuideInputs::validate`]).
+    pub fn set_ray_guides(&mut self, lobe: RayGuideLobe, inputs: RayGuideInputs) -> Result {
+        inputs.validate()?;
+
+        let p = &mut self.parameters;
+        match lobe {
+            RayGuideLobe::Diffuse => {
+                set_subrect_input(
+                    inputs.hit_distance,
+                    &mut self.diffuse_hit_distance_resource,
+                    &mut p.pInDiffuseHitDistance,
+                    &mut p.InDiffuseHitDistanceSubrectBase,
+                );
+                set_subrect_input(
+                    inputs.ray_direction,
+                    &mut self.diffuse_ray_direction_resource,
+                    &mut p.pInDiffuseRayDirection,
+                    &mut p.InDiffuseRayDirectionSubrectBase,
+                );
+                set_subrect_input(
+                    inputs.ray_direction_hit_distance,
+                    &mut self.diffuse_ray_direction_hit_distance_resource,
+                    &mut p.pInDiffuseRayDirectionHitDistance,
+                    &mut p.InDiffuseRayDirectionHitDistanceSubrectBase,
+                );
+            }
+            RayGuideLobe::Specular => {
+                set_subrect_input(
+                    inputs.hit_distance,
+                    &mut self.specular_hit_distance_resource,
+                    &mut p.pInSpecularHitDistance,
+                    &mut p.InSpecularHitDistanceSubrectBase,
+                );
+                set_subrect_input(
+                    inputs.ray_direction,
+                    &mut self.specular_ray_direction_resource,
+                    &mut p.pInSpecularRayDirection,
+                    &mut p.InSpecularRayDirectionSubrectBase,
+                );
+                set_subrect_input(
+                    inputs.ray_direction_hit_distance,
+                    &mut self.specular_ray_direction_hit_distance_resource,
+                    &mut p.pInSpecularRayDirectionHitDistance,
+                    &mut p.InSpecularRayDirectionHitDistanceSubrectBase,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets (or, with the default [`CompositionLayerInputs`], unsets)
+    /// the inputs of the composition `layer`, replacing the ones
+    /// previously set.
+    ///
+    /// Fails, leaving the layer intact, if the inputs are inconsistent
+    /// ([`CompositionLayerInputs::validate`]).
+    pub fn set_composition_layer(
         &mut self,
-        rendering_offset: [u32; 2],
-        rendering_size: [u32; 2],
-    ) {
-        self.parameters.InColorSubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+        layer: CompositionLayer,
+        inputs: CompositionLayerInputs,
+    ) -> Result {
+        inputs.validate(layer)?;
+
+        let p = &mut self.parameters;
+        let (before, after, guide) = match layer {
+            CompositionLayer::Particles => (
+                (
+                    &mut p.pInColorBeforeParticles,
+                    &mut p.InColorBeforeParticlesSubrectBase,
+                ),
+                (
+                    &mut p.pInColorAfterParticles,
+                    &mut p.InColorAfterParticlesSubrectBase,
+                ),
+                None,
+            ),
+            CompositionLayer::Transparency => (
+                (
+                    &mut p.pInColorBeforeTransparency,
+                    &mut p.InColorBeforeTransparencySubrectBase,
+                ),
+                (
+                    &mut p.pInColorAfterTransparency,
+                    &mut p.InColorAfterTransparencySubrectBase,
+                ),
+                None,
+            ),
+            CompositionLayer::Fog => (
+                (&mut p.pInColorBeforeFog, &mut p.InColorBeforeFogSubrectBase),
+                (&mut p.pInColorAfterFog, &mut p.InColorAfterFogSubrectBase),
+                None,
+            ),
+            CompositionLayer::ScreenSpaceSubsurfaceScattering => (
+                (
+                    &mut p.pInColorBeforeScreenSpaceSubsurfaceScattering,
+                    &mut p.InColorBeforeScreenSpaceSubsurfaceScatteringSubrectBase,
+                ),
+                (
+                    &mut p.pInColorAfterScreenSpaceSubsurfaceScattering,
+                    &mut p.InColorAfterScreenSpaceSubsurfaceScatteringSubrectBase,
+                ),
+                Some((
+                    &mut p.pInScreenSpaceSubsurfaceScatteringGuide,
+                    &mut p.InScreenSpaceSubsurfaceScatteringGuideSubrectBase,
+                )),
+            ),
+            CompositionLayer::ScreenSpaceRefraction => (
+                (
+                    &mut p.pInColorBeforeScreenSpaceRefraction,
+                    &mut p.InColorBeforeScreenSpaceRefractionSubrectBase,
+                ),
+                (
+                    &mut p.pInColorAfterScreenSpaceRefraction,
+                    &mut p.InColorAfterScreenSpaceRefractionSubrectBase,
+                ),
+                Some((
+                    &mut p.pInScreenSpaceRefractionGuide,
+                    &mut p.InScreenSpaceRefractionGuideSubrectBase,
+                )),
+            ),
+            CompositionLayer::DepthOfField => (
+                (
+                    &mut p.pInColorBeforeDepthOfField,
+                    &mut p.InColorBeforeDepthOfFieldSubrectBase,
+                ),
+                (
+                    &mut p.pInColorAfterDepthOfField,
+                    &mut p.InColorAfterDepthOfFieldSubrectBase,
+                ),
+                Some((
+                    &mut p.pInDepthOfFieldGuide,
+                    &mut p.InDepthOfFieldGuideSubrectBase,
+                )),
+            ),
         };
-        self.parameters.InDepthSubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+
+        let [before_resource, after_resource, guide_resource] =
+            &mut self.composition_layer_resources[layer as usize];
+        set_subrect_input(inputs.color_before, before_resource, before.0, before.1);
+        set_subrect_input(inputs.color_after, after_resource, after.0, after.1);
+        if let Some((pointer, base)) = guide {
+            set_subrect_input(inputs.guide, guide_resource, pointer, base);
+        }
+        Ok(())
+    }
+
+    /// Sets the type of the tone mapper the color output is going to
+    /// be passed to, for example, [`ToneMapperType::Aces`] for the
+    /// ACES HDR output. May change every frame.
+    pub fn set_tone_mapper_type(&mut self, tone_mapper_type: ToneMapperType) {
+        self.parameters.InToneMapperType = tone_mapper_type.into();
+    }
+
+    /// Sets the offset of the rendered region in the alpha input.
+    pub fn set_alpha_input_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InAlphaSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
         };
-        self.parameters.InTranslucencySubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+    }
+
+    /// Sets the offset of the region of the alpha output to write to.
+    pub fn set_alpha_output_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InOutputAlphaSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
         };
-        self.parameters.InMVSubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+    }
+
+    /// Sets the offset of the rendered region in the diffuse albedo.
+    pub fn set_diffuse_albedo_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InDiffuseAlbedoSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
         };
-        self.parameters.InRenderSubrectDimensions = NVSDK_NGX_Dimensions {
-            Width: rendering_size[0],
-            Height: rendering_size[1],
+    }
+
+    /// Sets the offset of the rendered region in the specular albedo.
+    pub fn set_specular_albedo_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InSpecularAlbedoSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
         };
     }
 
+    /// Sets the offset of the rendered region in the normals.
+    pub fn set_normals_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InNormalsSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
+        };
+    }
+
+    /// Sets the offset of the rendered region in the roughness.
+    pub fn set_roughness_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InRoughnessSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
+        };
+    }
+
+    /// Fails if any of the G-buffer inputs the feature requires is
+    /// missing, or the inputs don't match the `roughness_mode`: when
+    /// the roughness is packed into the normals, the normals must have
+    /// an alpha channel and the separate roughness must not be set,
+    /// otherwise the roughness is required.
+    pub(crate) fn validate_guide_inputs(&self, roughness_mode: RoughnessMode) -> Result {
+        let p = &self.parameters;
+        validation::require_image("diffuse albedo", p.pInDiffuseAlbedo)?;
+        validation::require_image("specular albedo", p.pInSpecularAlbedo)?;
+        let normals = validation::require_image("normals", p.pInNormals)?;
+        if roughness_mode == RoughnessMode::Packed {
+            validation::check_format(
+                "normals (with the roughness packed into the alpha channel)",
+                &normals,
+                validation::PACKED_ROUGHNESS_NORMALS_FORMATS,
+            )?;
+            if !p.pInRoughness.is_null() {
+                return Err(nvngx_sys::Error::Other(
+                    "The roughness is packed into the normals, so the separate roughness input must not be set.".to_owned(),
+                ));
+            }
+        } else {
+            validation::require_image("roughness", p.pInRoughness)?;
+        }
+        Ok(())
+    }
+
     /// Returns the filled Ray Reconstruction parameters.
+    ///
+    /// The camera matrices set are pointed to again, as the parameters
+    /// may have been moved since the matrices were set.
     pub(crate) fn get_rr_evaluation_parameters(
         &mut self,
     ) -> *mut nvngx_sys::NVSDK_NGX_VK_DLSSD_Eval_Params {
+        if !self.parameters.pInWorldToViewMatrix.is_null() {
+            self.parameters.pInWorldToViewMatrix = self.world_to_view_matrix.as_mut_ptr();
+        }
+        if !self.parameters.pInViewToClipMatrix.is_null() {
+            self.parameters.pInViewToClipMatrix = self.view_to_clip_matrix.as_mut_ptr();
+        }
         std::ptr::addr_of_mut!(self.parameters)
     }
 
@@ -199,6 +932,51 @@ impl RayReconstructionEvaluationParameters {
     // }
 }
 
+impl temporal_upscaler::private::Sealed for RayReconstructionEvaluationParameters {
+    fn get_common_fields(&mut self) -> temporal_upscaler::private::CommonEvaluationFields<'_> {
+        let p = &mut self.parameters;
+        temporal_upscaler::private::CommonEvaluationFields {
+            color_input: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.input_color_resource,
+                pointer: &mut p.pInColor,
+            },
+            color_output: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.output_color_resource,
+                pointer: &mut p.pInOutput,
+            },
+            depth: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.depth_resource,
+                pointer: &mut p.pInDepth,
+            },
+            motion_vectors: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.motion_vectors_resource,
+                pointer: &mut p.pInMotionVectors,
+            },
+            motion_vectors_scale: [&mut p.InMVScaleX, &mut p.InMVScaleY],
+            jitter_offset: [&mut p.InJitterOffsetX, &mut p.InJitterOffsetY],
+            reset: &mut p.InReset,
+            render_subrect_dimensions: &mut p.InRenderSubrectDimensions,
+        }
+    }
+
+    /// Sets the offsets of the color, alpha, depth, translucency,
+    /// motion vectors and the G-buffer.
+    fn set_all_subrect_bases(&mut self, base: NVSDK_NGX_Coordinates) {
+        let p = &mut self.parameters;
+        p.InColorSubrectBase = base;
+        p.InAlphaSubrectBase = base;
+        p.InDepthSubrectBase = base;
+        p.InTranslucencySubrectBase = base;
+        p.InMVSubrectBase = base;
+        p.InDiffuseAlbedoSubrectBase = base;
+        p.InSpecularAlbedoSubrectBase = base;
+        p.InNormalsSubrectBase = base;
+        p.InRoughnessSubrectBase = base;
+    }
+}
+
+impl TemporalUpscalerInputs for RayReconstructionEvaluationParameters {}
+
 /// A helpful type alias to quickly mention "DLSS-RR".
 pub type RRFeature = RayReconstructionFeature;
 
@@ -209,14 +987,20 @@ pub struct RayReconstructionFeature {
     parameters: RayReconstructionEvaluationParameters,
     rendering_resolution: vk::Extent2D,
     target_resolution: vk::Extent2D,
+    roughness_mode: RoughnessMode,
+    flags: DlssFeatureFlags,
+    output_subrects_enabled: bool,
+    validation_enabled: bool,
 }
 
 impl RayReconstructionFeature {
-    /// Creates a new Super Sampling feature.
+    /// Creates a new Ray Reconstruction feature from the `feature`
+    /// created with the `create_parameters`, which the resolutions, the
+    /// roughness mode, the flags and the output subrects are taken
+    /// from.
     pub fn new(
         feature: Feature,
-        rendering_resolution: vk::Extent2D,
-        target_resolution: vk::Extent2D,
+        create_parameters: &RayReconstructionCreateParameters,
     ) -> Result<Self> {
         if !feature.is_ray_reconstruction() {
             return Err(nvngx_sys::Error::Other(
@@ -227,8 +1011,16 @@ impl RayReconstructionFeature {
         Ok(Self {
             feature,
             parameters: RayReconstructionEvaluationParameters::new(),
-            rendering_resolution,
-            target_resolution,
+            rendering_resolution: vk::Extent2D::default()
+                .width(create_parameters.0.InWidth)
+                .height(create_parameters.0.InHeight),
+            target_resolution: vk::Extent2D::default()
+                .width(create_parameters.0.InTargetWidth)
+                .height(create_parameters.0.InTargetHeight),
+            roughness_mode: create_parameters.get_roughness_mode()?,
+            flags: create_parameters.get_flags(),
+            output_subrects_enabled: create_parameters.is_output_subrects_enabled(),
+            validation_enabled: false,
         })
     }
 
@@ -254,6 +1046,23 @@ impl RayReconstructionFeature {
         self.target_resolution
     }
 
+    /// Returns the flags the feature was created with.
+    pub const fn get_flags(&self) -> DlssFeatureFlags {
+        self.flags
+    }
+
+    /// Returns the roughness mode the feature was created with.
+    pub const fn get_roughness_mode(&self) -> RoughnessMode {
+        self.roughness_mode
+    }
+
+    /// Returns the texture LOD bias recommended for the render
+    /// resolution. See [`get_recommended_lod_bias`]
+    /// and [`apply_lod_bias`].
+    pub fn get_recommended_lod_bias(&self) -> f32 {
+        get_recommended_lod_bias(self.rendering_resolution, self.target_resolution)
+    }
+
     // /// Attempts to create the [`RayReconstructionFeature`] with the default
     // /// settings preset.
     // pub fn try_default() -> Result<Self> {
@@ -261,11 +1070,11 @@ impl RayReconstructionFeature {
     //     Self::new(parameters)
     // }
 
-    /// See [`FeatureParameters::is_super_sampling_initialised`].
+    /// See [`FeatureParameters::is_ray_reconstruction_initialised`].
     pub fn is_initialised(&self) -> bool {
         self.feature
             .get_parameters()
-            .is_super_sampling_initialised()
+            .is_ray_reconstruction_initialised()
     }
 
     /// Returns the evaluation parameters.
@@ -273,8 +1082,46 @@ impl RayReconstructionFeature {
         &mut self.parameters
     }
 
+    /// Enables or disables the validation of the evaluation inputs
+    /// before every [`Self::evaluate`], disabled by default. See the
+    /// [`validation`] module for what is checked.
+    pub fn set_validation_enabled(&mut self, enabled: bool) {
+        self.validation_enabled = enabled;
+    }
+
+    /// Returns [`true`] if the validation of the evaluation inputs is
+    /// enabled.
+    pub fn is_validation_enabled(&self) -> bool {
+        self.validation_enabled
+    }
+
     /// Evaluates the feature.
+    ///
+    /// Fails without recording anything if any of the required
+    /// G-buffer inputs (the diffuse and specular albedo, the normals
+    /// and the roughness, unless packed into the normals) is missing,
+    /// if only one of the alpha input and output is set or either has
+    /// an unsupported format, or if the validation fails
+    /// ([`Self::set_validation_enabled`]).
     pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        self.parameters.validate_guide_inputs(self.roughness_mode)?;
+        validation::validate_alpha_input_output(
+            self.parameters.parameters.pInAlpha,
+            self.parameters.parameters.pInOutputAlpha,
+        )?;
+        if self.validation_enabled {
+            let p = &self.parameters.parameters;
+            validation::validate_common_inputs(
+                p.pInColor,
+                p.pInOutput,
+                p.pInDepth,
+                p.pInMotionVectors,
+                self.rendering_resolution,
+                self.target_resolution,
+                self.flags,
+                self.output_subrects_enabled,
+            )?;
+        }
         Result::from(unsafe {
             nvngx_sys::HELPERS_NGX_VULKAN_EVALUATE_DLSSD_EXT(
                 command_buffer,
@@ -285,3 +1132,266 @@ impl RayReconstructionFeature {
         })
     }
 }
+
+impl TemporalUpscaler for RayReconstructionFeature {
+    fn get_inputs_mut(&mut self) -> &mut dyn TemporalUpscalerInputs {
+        &mut self.parameters
+    }
+
+    fn get_rendering_resolution(&self) -> vk::Extent2D {
+        self.rendering_resolution
+    }
+
+    fn get_target_resolution(&self) -> vk::Extent2D {
+        self.target_resolution
+    }
+
+    fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        RayReconstructionFeature::evaluate(self, command_buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CompositionLayer, CompositionLayerInputs, DenoiseMode, DepthType,
+        NVSDK_NGX_DLSS_Denoise_Mode, NVSDK_NGX_DLSS_Depth_Type, NVSDK_NGX_DLSS_Roughness_Mode,
+        RayGuideInputs, RayGuideLobe, RayReconstructionCreateParameters,
+        RayReconstructionEvaluationParameters, RayReconstructionOptimalSettings, RoughnessMode,
+    };
+    use crate::VkImageResourceDescription;
+    use crate::{CameraMatrix, DlssFeatureFlags, QualityMode, SuperSamplingOptimalSettings};
+    use ash::vk;
+
+    #[test]
+    fn guide_inputs_are_required() {
+        let unpacked = RoughnessMode::Unpacked;
+        let packed = RoughnessMode::Packed;
+
+        let mut parameters = RayReconstructionEvaluationParameters::new();
+        parameters.set_diffuse_albedo(VkImageResourceDescription::default());
+        parameters.set_specular_albedo(VkImageResourceDescription::default());
+        let error = parameters.validate_guide_inputs(packed).unwrap_err();
+        assert!(error.to_string().contains("normals"));
+
+        parameters.set_normals(VkImageResourceDescription {
+            format: vk::Format::R16G16B16A16_SFLOAT,
+            ..Default::default()
+        });
+        assert!(parameters.validate_guide_inputs(packed).is_ok());
+        let error = parameters.validate_guide_inputs(unpacked).unwrap_err();
+        assert!(error.to_string().contains("roughness"));
+
+        parameters.set_roughness(VkImageResourceDescription::default());
+        assert!(parameters.validate_guide_inputs(unpacked).is_ok());
+    }
+
+    #[test]
+    fn packed_roughness_requires_alpha_normals() {
+        let packed = RoughnessMode::Packed;
+
+        let mut parameters = RayReconstructionEvaluationParameters::new();
+        parameters.set_diffuse_albedo(VkImageResourceDescription::default());
+        parameters.set_specular_albedo(VkImageResourceDescription::default());
+        parameters.set_normals(VkImageResourceDescription {
+            format: vk::Format::B10G11R11_UFLOAT_PACK32,
+            ..Default::default()
+        });
+        let error = parameters.validate_guide_inputs(packed).unwrap_err();
+        assert!(error.to_string().contains("B10G11R11"));
+
+        parameters.set_normals(VkImageResourceDescription {
+            format: vk::Format::R8G8B8A8_SNORM,
+            ..Default::default()
+        });
+        parameters.set_roughness(VkImageResourceDescription::default());
+        let error = parameters.validate_guide_inputs(packed).unwrap_err();
+        assert!(error.to_string().contains("separate roughness"));
+    }
+
+    #[test]
+    fn combined_and_separate_ray_guides_are_exclusive() {
+        let mut parameters = RayReconstructionEvaluationParameters::new();
+        let both = RayGuideInputs {
+            hit_distance: Some(VkImageResourceDescription::default().into()),
+            ray_direction_hit_distance: Some(VkImageResourceDescription::default().into()),
+            ..Default::default()
+        };
+        assert!(parameters
+            .set_ray_guides(RayGuideLobe::Specular, both)
+            .is_err());
+        assert!(parameters.parameters.pInSpecularHitDistance.is_null());
+
+        let combined = RayGuideInputs {
+            ray_direction_hit_distance: Some(VkImageResourceDescription::default().into()),
+            ..Default::default()
+        };
+        parameters
+            .set_ray_guides(RayGuideLobe::Specular, combined)
+            .unwrap();
+        assert!(!parameters
+            .parameters
+            .pInSpecularRayDirectionHitDistance
+            .is_null());
+        assert!(parameters
+            .parameters
+            .pInDiffuseRayDirectionHitDistance
+            .is_null());
+
+        parameters
+            .set_ray_guides(RayGuideLobe::Specular, RayGuideInputs::default())
+            .unwrap();
+        assert!(parameters
+            .parameters
+            .pInSpecularRayDirectionHitDistance
+            .is_null());
+    }
+
+    #[test]
+    fn composition_layers() {
+        let image = || Some(VkImageResourceDescription::default().into());
+        let mut parameters = RayReconstructionEvaluationParameters::new();
+
+        let only_before = CompositionLayerInputs {
+            color_before: image(),
+            ..Default::default()
+        };
+        assert!(parameters
+            .set_composition_layer(CompositionLayer::Fog, only_before)
+            .is_err());
+
+        let with_guide = CompositionLayerInputs {
+            color_before: image(),
+            color_after: image(),
+            guide: image(),
+        };
+        assert!(parameters
+            .set_composition_layer(CompositionLayer::Particles, with_guide)
+            .is_err());
+        parameters
+            .set_composition_layer(CompositionLayer::DepthOfField, with_guide)
+            .unwrap();
+        assert!(!parameters.parameters.pInDepthOfFieldGuide.is_null());
+        assert!(!parameters.parameters.pInColorAfterDepthOfField.is_null());
+        assert!(parameters.parameters.pInColorAfterFog.is_null());
+    }
+
+    #[test]
+    fn camera_matrices_follow_the_parameters() {
+        let mut parameters = RayReconstructionEvaluationParameters::new();
+        let mut view = CameraMatrix::IDENTITY;
+        view.0[3][2] = -5.0;
+        parameters.set_world_to_view_matrix(view);
+        parameters.set_view_to_clip_matrix(CameraMatrix::IDENTITY);
+
+        // Moves the parameters to the heap, away from where the
+        // matrices were set.
+        let mut parameters = Box::new(parameters);
+        let p = parameters.get_rr_evaluation_parameters();
+        let (world_to_view, view_to_clip) =
+            unsafe { ((*p).pInWorldToViewMatrix, (*p).pInViewToClipMatrix) };
+        assert_eq!(world_to_view, parameters.world_to_view_matrix.as_mut_ptr());
+        assert_eq!(unsafe { *world_to_view.add(14) }, -5.0);
+        assert_eq!(view_to_clip, parameters.view_to_clip_matrix.as_mut_ptr());
+    }
+
+    #[test]
+    fn super_sampling_only_flags_are_rejected() {
+        let parameters = |flags| {
+            RayReconstructionCreateParameters::new(
+                960,
+                540,
+                1920,
+                1080,
+                None,
+                None,
+                None,
+                DepthType::Linear,
+                flags,
+            )
+        };
+        let flags = DlssFeatureFlags::new().hdr(true).auto_exposure(true);
+        assert!(parameters(flags).check_flags().is_ok());
+
+        let error = parameters(flags.do_sharpening(true))
+            .check_flags()
+            .unwrap_err();
+        assert!(error.to_string().contains("sharpening"));
+        let error = parameters(flags.alpha_upscaling(true))
+            .check_flags()
+            .unwrap_err();
+        assert!(error.to_string().contains("alpha upscaling"));
+    }
+
+    #[test]
+    fn create_parameters_from_optimal_settings() {
+        let settings = RayReconstructionOptimalSettings {
+            settings: SuperSamplingOptimalSettings {
+                render_width: 960,
+                render_height: 540,
+                target_width: 1920,
+                target_height: 1080,
+                desired_quality_level: QualityMode::Performance,
+                dynamic_min_render_width: 960,
+                dynamic_max_render_width: 960,
+                dynamic_min_render_height: 540,
+                dynamic_max_render_height: 540,
+            },
+        };
+        let flags = DlssFeatureFlags::new().hdr(true).depth_inverted(true);
+
+        let parameters = RayReconstructionCreateParameters::from_optimal_settings(
+            settings,
+            flags,
+            DepthType::Hardware,
+        )
+        .with_output_subrects(true);
+        assert_eq!(parameters.get_flags(), flags);
+        assert_eq!(parameters.get_depth_type().unwrap(), DepthType::Hardware);
+        assert!(parameters.is_output_subrects_enabled());
+
+        let parameters = RayReconstructionCreateParameters::from(settings);
+        assert_eq!(parameters.get_flags(), DlssFeatureFlags::new());
+        assert_eq!(parameters.get_depth_type().unwrap(), DepthType::Linear);
+        assert_eq!(
+            parameters.get_denoise_mode().unwrap(),
+            DenoiseMode::DlUnified
+        );
+        assert_eq!(
+            parameters.get_roughness_mode().unwrap(),
+            RoughnessMode::Unpacked
+        );
+
+        let parameters = RayReconstructionCreateParameters::new(
+            960,
+            540,
+            1920,
+            1080,
+            None,
+            None,
+            Some(RoughnessMode::Packed),
+            DepthType::Linear,
+            DlssFeatureFlags::new(),
+        );
+        assert_eq!(
+            parameters.get_roughness_mode().unwrap(),
+            RoughnessMode::Packed
+        );
+    }
+
+    #[test]
+    fn mode_conversion() {
+        for mode in [DenoiseMode::Off, DenoiseMode::DlUnified] {
+            let raw = NVSDK_NGX_DLSS_Denoise_Mode::from(mode);
+            assert_eq!(DenoiseMode::try_from(raw).unwrap(), mode);
+        }
+        for depth_type in [DepthType::Linear, DepthType::Hardware] {
+            let raw = NVSDK_NGX_DLSS_Depth_Type::from(depth_type);
+            assert_eq!(DepthType::try_from(raw).unwrap(), depth_type);
+        }
+        for mode in [RoughnessMode::Unpacked, RoughnessMode::Packed] {
+            let raw = NVSDK_NGX_DLSS_Roughness_Mode::from(mode);
+            assert_eq!(RoughnessMode::try_from(raw).unwrap(), mode);
+        }
+    }
+}
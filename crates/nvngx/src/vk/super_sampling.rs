@@ -1,7 +1,8 @@
 //! Describes and implements the interface for the DLSS feature.
 
 use nvngx_sys::{
-    NVSDK_NGX_DLSS_Create_Params, NVSDK_NGX_DLSS_Feature_Flags, NVSDK_NGX_VK_DLSS_Eval_Params,
+    NVSDK_NGX_DLSS_Create_Params, NVSDK_NGX_DLSS_Feature_Flags, NVSDK_NGX_DLSS_Hint_Render_Preset,
+    NVSDK_NGX_ToneMapperType, NVSDK_NGX_VK_DLSS_Eval_Params,
 };
 
 use super::*;
@@ -26,25 +27,170 @@ pub struct SuperSamplingOptimalSettings {
     /// will upscale to.
     pub target_height: u32,
     /// The requested quality level.
-    pub desired_quality_level: nvngx_sys::NVSDK_NGX_PerfQuality_Value,
-    /// TODO:
+    pub desired_quality_level: QualityMode,
+    /// The minimal render width the feature supports when the render
+    /// resolution is changed dynamically (every frame).
     pub dynamic_min_render_width: u32,
-    /// TODO:
+    /// The maximal render width the feature supports when the render
+    /// resolution is changed dynamically (every frame).
     pub dynamic_max_render_width: u32,
-    /// TODO:
+    /// The minimal render height the feature supports when the render
+    /// resolution is changed dynamically (every frame).
     pub dynamic_min_render_height: u32,
-    /// TODO:
+    /// The maximal render height the feature supports when the render
+    /// resolution is changed dynamically (every frame).
     pub dynamic_max_render_height: u32,
 }
 
+/// The NGX helper querying the optimal settings of a feature: the
+/// [`nvngx_sys::HELPERS_NGX_DLSS_GET_OPTIMAL_SETTINGS`] or the
+/// [`nvngx_sys::HELPERS_NGX_DLSSD_GET_OPTIMAL_SETTINGS`].
+pub(crate) type OptimalSettingsQuery = unsafe extern "C" fn(
+    *mut nvngx_sys::NVSDK_NGX_Parameter,
+    std::os::raw::c_uint,
+    std::os::raw::c_uint,
+    nvngx_sys::NVSDK_NGX_PerfQuality_Value,
+    *mut std::os::raw::c_uint,
+    *mut std::os::raw::c_uint,
+    *mut std::os::raw::c_uint,
+    *mut std::os::raw::c_uint,
+    *mut std::os::raw::c_uint,
+    *mut std::os::raw::c_uint,
+    *mut f32,
+) -> nvngx_sys::NVSDK_NGX_Result;
+
 impl SuperSamplingOptimalSettings {
     /// Returns a set of optimal settings for the desired parameter
     /// set, render dimensions and quality level.
+    ///
+    /// For the [`QualityMode::Dlaa`], when the NGX reports it as
+    /// supported, the render resolution (including the dynamic bounds)
+    /// is the target resolution.
     pub fn get_optimal_settings(
         parameters: &FeatureParameters,
         target_width: u32,
         target_height: u32,
-        desired_quality_level: nvngx_sys::NVSDK_NGX_PerfQuality_Value,
+        desired_quality_level: QualityMode,
+    ) -> Result<Self> {
+        Self::get_supported_optimal_settings(
+            nvngx_sys::HELPERS_NGX_DLSS_GET_OPTIMAL_SETTINGS,
+            parameters,
+            target_width,
+            target_height,
+            desired_quality_level,
+        )
+    }
+
+    /// Queries the optimal settings using the `query`, failing if the
+    /// quality level isn't supported.
+    pub(crate) fn get_supported_optimal_settings(
+        query: OptimalSettingsQuery,
+        parameters: &FeatureParameters,
+        target_width: u32,
+        target_height: u32,
+        desired_quality_level: QualityMode,
+    ) -> Result<Self> {
+        let settings = Self::query_optimal_settings(
+            query,
+            parameters,
+            target_width,
+            target_height,
+            desired_quality_level,
+        )?;
+
+        if !settings.is_supported() {
+            return Err(nvngx_sys::Error::Other(format!(
+                "The requested quality level isn't supported: {desired_quality_level:?}"
+            )));
+        }
+
+        Ok(settings)
+    }
+
+    /// Returns the optimal settings for every quality level of
+    /// [`QualityMode::ALL`] for the desired target
+    /// dimensions, for example, to populate a settings menu.
+    ///
+    /// Unlike [`Self::get_optimal_settings`], this doesn't fail when a
+    /// quality level isn't supported, marking it as such instead. An
+    /// error is only returned when the optimal settings can't be
+    /// queried at all
+    /// ([`nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate`]).
+    /// Any other error of the query of a single quality level marks
+    /// the quality level as unsupported, without the error itself
+    /// being reported.
+    pub fn get_optimal_settings_table(
+        parameters: &FeatureParameters,
+        target_width: u32,
+        target_height: u32,
+    ) -> Result<Vec<SuperSamplingQualityLevelSettings>> {
+        QualityMode::ALL
+            .iter()
+            .map(|&quality_level| {
+                let settings = match Self::query_optimal_settings(
+                    nvngx_sys::HELPERS_NGX_DLSS_GET_OPTIMAL_SETTINGS,
+                    parameters,
+                    target_width,
+                    target_height,
+                    quality_level,
+                ) {
+                    Ok(settings) => Some(settings).filter(Self::is_supported),
+                    Err(nvngx_sys::Error::Internal(
+                        nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+                    )) => {
+                        return Err(nvngx_sys::Error::Internal(
+                            nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+                        ))
+                    }
+                    Err(_) => None,
+                };
+
+                Ok(SuperSamplingQualityLevelSettings {
+                    quality_level,
+                    settings,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the effective scale factor: how many times the target
+    /// resolution is bigger than the render resolution (for example,
+    /// `1.5` for the "quality" mode and `1.0` for DLAA), computed
+    /// from the widths. Returns [`None`] if the quality level isn't
+    /// supported (the render resolution is zero).
+    pub fn get_scale_factor(&self) -> Option<f32> {
+        self.is_supported()
+            .then(|| self.target_width as f32 / self.render_width as f32)
+    }
+
+    /// Returns the range of the render resolutions supported when the
+    /// render resolution is changed dynamically.
+    pub fn get_dynamic_resolution_range(&self) -> DynamicResolutionRange {
+        DynamicResolutionRange {
+            min: vk::Extent2D::default()
+                .width(self.dynamic_min_render_width)
+                .height(self.dynamic_min_render_height),
+            max: vk::Extent2D::default()
+                .width(self.dynamic_max_render_width)
+                .height(self.dynamic_max_render_height),
+        }
+    }
+
+    /// Returns [`true`] if the settings contain a valid render
+    /// resolution, which isn't the case when the quality level isn't
+    /// supported.
+    fn is_supported(&self) -> bool {
+        self.render_width != 0 && self.render_height != 0
+    }
+
+    /// Queries the NGX for the optimal settings using the `query`,
+    /// without checking if the quality level is supported.
+    fn query_optimal_settings(
+        query: OptimalSettingsQuery,
+        parameters: &FeatureParameters,
+        target_width: u32,
+        target_height: u32,
+        desired_quality_level: QualityMode,
     ) -> Result<Self> {
         let mut settings = Self {
             render_width: 0,
@@ -60,11 +206,11 @@ impl SuperSamplingOptimalSettings {
         // The sharpness is deprecated, should stay zero.
         let mut sharpness = 0.0f32;
         Result::from(unsafe {
-            nvngx_sys::HELPERS_NGX_DLSS_GET_OPTIMAL_SETTINGS(
+            query(
                 parameters.0,
                 settings.target_width,
                 settings.target_height,
-                settings.desired_quality_level,
+                settings.desired_quality_level.into(),
                 &mut settings.render_width as *mut _,
                 &mut settings.render_height as *mut _,
                 &mut settings.dynamic_max_render_width as *mut _,
@@ -75,20 +221,413 @@ impl SuperSamplingOptimalSettings {
             )
         })?;
 
-        if settings.render_height == 0 || settings.render_width == 0 {
+        if desired_quality_level == QualityMode::Dlaa && settings.is_supported() {
+            // The anti-aliasing only, no upscaling.
+            settings.render_width = target_width;
+            settings.render_height = target_height;
+            settings.dynamic_min_render_width = target_width;
+            settings.dynamic_max_render_width = target_width;
+            settings.dynamic_min_render_height = target_height;
+            settings.dynamic_max_render_height = target_height;
+        }
+        Ok(settings)
+    }
+}
+
+/// The range of the render resolutions the SuperSampling feature can
+/// upscale from without being recreated, as returned by
+/// [`SuperSamplingOptimalSettings::get_dynamic_resolution_range`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DynamicResolutionRange {
+    /// The minimal render resolution.
+    pub min: vk::Extent2D,
+    /// The maximal render resolution.
+    pub max: vk::Extent2D,
+}
+
+impl DynamicResolutionRange {
+    /// Returns [`true`] if the `render_size` is within the range
+    /// (inclusive).
+    pub fn contains(&self, render_size: vk::Extent2D) -> bool {
+        (self.min.width..=self.max.width).contains(&render_size.width)
+            && (self.min.height..=self.max.height).contains(&render_size.height)
+    }
+}
+
+/// The optimal settings of a single quality level, as returned by
+/// [`SuperSamplingOptimalSettings::get_optimal_settings_table`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SuperSamplingQualityLevelSettings {
+    /// The quality level.
+    pub quality_level: QualityMode,
+    /// The optimal settings, or [`None`] if the quality level isn't
+    /// supported for the target resolution.
+    pub settings: Option<SuperSamplingOptimalSettings>,
+}
+
+impl SuperSamplingQualityLevelSettings {
+    /// Returns [`true`] if the quality level is supported.
+    pub fn is_supported(&self) -> bool {
+        self.settings.is_some()
+    }
+}
+
+/// Video memory statistics of the SuperSampling feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SuperSamplingStats {
+    /// The amount of video memory (in bytes) allocated by the
+    /// SuperSampling features.
+    pub vram_allocated_bytes: u64,
+}
+
+impl SuperSamplingStats {
+    /// Returns the video memory statistics of the SuperSampling
+    /// feature, using the
+    /// [`nvngx_sys::NVSDK_NGX_Parameter_DLSSGetStatsCallback`].
+    ///
+    /// The `parameters` must be obtained via
+    /// [`FeatureParameters::get_capability_parameters`], otherwise the
+    /// callback isn't available.
+    ///
+    /// The value reported is the memory currently allocated by the
+    /// created SuperSampling features. To estimate the memory of a
+    /// feature before creating it, see
+    /// [`SuperSamplingVramEstimate::get_vram_estimate`].
+    pub fn get_stats(parameters: &FeatureParameters) -> Result<Self> {
+        parameters
+            .get_allocated_vram_bytes(nvngx_sys::NVSDK_NGX_Parameter_DLSSGetStatsCallback)
+            .map(|vram_allocated_bytes| Self {
+                vram_allocated_bytes,
+            })
+    }
+}
+
+/// An estimate of the video memory the SuperSampling feature requires
+/// for the given resolutions and quality level.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SuperSamplingVramEstimate {
+    /// The render resolution.
+    pub render_size: vk::Extent2D,
+    /// The target resolution.
+    pub target_size: vk::Extent2D,
+    /// The quality level.
+    pub quality_level: QualityMode,
+    /// The estimated amount of video memory in bytes.
+    pub estimated_vram_bytes: u64,
+}
+
+impl SuperSamplingVramEstimate {
+    /// Returns an estimate of the video memory a SuperSampling feature
+    /// of the resolutions, the quality level and the `flags` requires,
+    /// including the scratch buffer provided by the allocator of the
+    /// `system` ([`System::set_allocator`]), if any.
+    ///
+    /// The NGX can't estimate the memory of a SuperSampling feature
+    /// without creating it, so a probe feature is created with the
+    /// `command_buffer` and released right away, reporting the
+    /// difference of the [`SuperSamplingStats`] before and after. The
+    /// commands the NGX records into the `command_buffer` refer to the
+    /// released feature, so the command buffer must be reset rather
+    /// than submitted afterwards.
+    pub fn get_vram_estimate(
+        system: &System,
+        command_buffer: vk::CommandBuffer,
+        render_size: vk::Extent2D,
+        target_size: vk::Extent2D,
+        quality_level: QualityMode,
+        flags: DlssFeatureFlags,
+    ) -> Result<Self> {
+        let estimated_vram_bytes = system.measure_feature_vram_bytes(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+            nvngx_sys::NVSDK_NGX_Parameter_DLSSGetStatsCallback,
+            |feature_parameters| {
+                system.create_super_sampling_feature(
+                    command_buffer,
+                    feature_parameters,
+                    SuperSamplingCreateParameters::new(
+                        render_size.width,
+                        render_size.height,
+                        target_size.width,
+                        target_size.height,
+                        Some(quality_level),
+                        flags,
+                    ),
+                )
+            },
+        )?;
+
+        Ok(Self {
+            render_size,
+            target_size,
+            quality_level,
+            estimated_vram_bytes,
+        })
+    }
+}
+
+/// The tone mapper the color output of a feature is passed to after
+/// the evaluation, letting the feature account for it.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ToneMapperType {
+    /// The "string" tone mapper. This is the default.
+    #[default]
+    String,
+    /// The Reinhard tone mapper.
+    Reinhard,
+    /// The "one over luma" tone mapper.
+    OneOverLuma,
+    /// The ACES (Academy Color Encoding System) tone mapper.
+    Aces,
+}
+
+impl From<ToneMapperType> for NVSDK_NGX_ToneMapperType {
+    fn from(value: ToneMapperType) -> Self {
+        match value {
+            ToneMapperType::String => Self::NVSDK_NGX_TONEMAPPER_STRING,
+            ToneMapperType::Reinhard => Self::NVSDK_NGX_TONEMAPPER_REINHARD,
+            ToneMapperType::OneOverLuma => Self::NVSDK_NGX_TONEMAPPER_ONEOVERLUMA,
+            ToneMapperType::Aces => Self::NVSDK_NGX_TONEMAPPER_ACES,
+        }
+    }
+}
+
+/// The DLSS feature creation flags.
+///
+/// A typed builder of the [`NVSDK_NGX_DLSS_Feature_Flags`]. No flag
+/// is set by default.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DlssFeatureFlags(pub(crate) std::os::raw::c_int);
+
+impl DlssFeatureFlags {
+    /// Creates a new set of flags with no flag set.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    const fn with(self, flag: NVSDK_NGX_DLSS_Feature_Flags, enabled: bool) -> Self {
+        if enabled {
+            Self(self.0 | flag.0)
+        } else {
+            Self(self.0 & !flag.0)
+        }
+    }
+
+    const fn contains(self, flag: NVSDK_NGX_DLSS_Feature_Flags) -> bool {
+        self.0 & flag.0 == flag.0
+    }
+
+    /// Sets whether the color input is in the high dynamic range.
+    pub const fn hdr(self, enabled: bool) -> Self {
+        self.with(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_IsHDR,
+            enabled,
+        )
+    }
+
+    /// Sets whether the motion vectors are rendered at the render
+    /// (low) resolution, rather than at the target resolution.
+    pub const fn mv_low_res(self, enabled: bool) -> Self {
+        self.with(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_MVLowRes,
+            enabled,
+        )
+    }
+
+    /// Sets whether the motion vectors include the jitter.
+    pub const fn mv_jittered(self, enabled: bool) -> Self {
+        self.with(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_MVJittered,
+            enabled,
+        )
+    }
+
+    /// Sets whether the depth is inverted (the "reverse-Z", with the
+    /// near plane at `1.0`).
+    pub const fn depth_inverted(self, enabled: bool) -> Self {
+        self.with(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_DepthInverted,
+            enabled,
+        )
+    }
+
+    /// Sets whether the output should be sharpened. Deprecated by
+    /// NVIDIA and ignored by the recent versions of DLSS.
+    pub const fn do_sharpening(self, enabled: bool) -> Self {
+        self.with(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_DoSharpening,
+            enabled,
+        )
+    }
+
+    /// Sets whether the exposure should be computed by the feature
+    /// itself, instead of being provided via an exposure texture.
+    pub const fn auto_exposure(self, enabled: bool) -> Self {
+        self.with(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AutoExposure,
+            enabled,
+        )
+    }
+
+    /// Sets whether the alpha channel of the color input should be
+    /// upscaled too
+    /// ([`NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AlphaUpscaling`]).
+    ///
+    /// Per the "Alpha Upscaling Support" section of the DLSS
+    /// Programming Guide, the DLSS is intended for the RGB images only
+    /// by default, and this flag enables the (experimental) upscaling
+    /// of the RGBA images: the alpha is upscaled along with the color,
+    /// rather than passed through. The guide recommends the color
+    /// input to be premultiplied by the alpha for the best results,
+    /// and notes that the evaluation takes about 15-25% longer. It
+    /// doesn't state whether the color output is premultiplied, nor
+    /// what the alpha channel of the output holds with the flag
+    /// disabled, so neither should be relied upon.
+    ///
+    /// Both the color input and output must then have an alpha channel
+    /// of at least 8 bits, which is checked at every evaluation
+    /// ([`SuperSamplingFeature::evaluate`]).
+    pub const fn alpha_upscaling(self, enabled: bool) -> Self {
+        self.with(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AlphaUpscaling,
+            enabled,
+        )
+    }
+
+    /// See [`Self::hdr`].
+    pub const fn is_hdr(self) -> bool {
+        self.contains(NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_IsHDR)
+    }
+
+    /// See [`Self::mv_low_res`].
+    pub const fn is_mv_low_res(self) -> bool {
+        self.contains(NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_MVLowRes)
+    }
+
+    /// See [`Self::mv_jittered`].
+    pub const fn is_mv_jittered(self) -> bool {
+        self.contains(NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_MVJittered)
+    }
+
+    /// See [`Self::depth_inverted`].
+    pub const fn is_depth_inverted(self) -> bool {
+        self.contains(NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_DepthInverted)
+    }
+
+    /// See [`Self::do_sharpening`].
+    pub const fn is_sharpening(self) -> bool {
+        self.contains(NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_DoSharpening)
+    }
+
+    /// See [`Self::auto_exposure`].
+    pub const fn is_auto_exposure(self) -> bool {
+        self.contains(NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AutoExposure)
+    }
+
+    /// See [`Self::alpha_upscaling`].
+    pub const fn is_alpha_upscaling(self) -> bool {
+        self.contains(NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AlphaUpscaling)
+    }
+}
+
+impl From<DlssFeatureFlags> for NVSDK_NGX_DLSS_Feature_Flags {
+    fn from(value: DlssFeatureFlags) -> Self {
+        Self(value.0)
+    }
+}
+
+impl TryFrom<NVSDK_NGX_DLSS_Feature_Flags> for DlssFeatureFlags {
+    type Error = nvngx_sys::Error;
+
+    /// Fails if the
+    /// [`NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_IsInvalid`]
+    /// bit is set.
+    fn try_from(value: NVSDK_NGX_DLSS_Feature_Flags) -> Result<Self, Self::Error> {
+        let invalid = NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_IsInvalid;
+        if value.0 & invalid.0 != 0 {
             return Err(nvngx_sys::Error::Other(format!(
-                "The requested quality level isn't supported: {desired_quality_level:?}"
+                "The DLSS feature flags are marked as invalid: {value:?}"
             )));
         }
+        Ok(Self(value.0))
+    }
+}
 
-        Ok(settings)
+/// The render preset (the version of the neural network model) the
+/// SuperSampling feature is hinted to use, see
+/// [`SuperSamplingFeature::new_dlaa`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RenderPreset {
+    /// Lets the NGX choose the preset. This is the default.
+    #[default]
+    Default,
+    /// The preset F.
+    F,
+    /// The preset G.
+    G,
+    /// The preset J.
+    J,
+    /// The preset K.
+    K,
+    /// The preset L.
+    L,
+    /// The preset M.
+    M,
+    /// The preset N.
+    N,
+    /// The preset O.
+    O,
+}
+
+impl From<RenderPreset> for NVSDK_NGX_DLSS_Hint_Render_Preset {
+    fn from(value: RenderPreset) -> Self {
+        match value {
+            RenderPreset::Default => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_Default,
+            RenderPreset::F => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_F,
+            RenderPreset::G => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_G,
+            RenderPreset::J => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_J,
+            RenderPreset::K => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_K,
+            RenderPreset::L => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_L,
+            RenderPreset::M => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_M,
+            RenderPreset::N => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_N,
+            RenderPreset::O => Self::NVSDK_NGX_DLSS_Hint_Render_Preset_O,
+        }
+    }
+}
+
+impl TryFrom<NVSDK_NGX_DLSS_Hint_Render_Preset> for RenderPreset {
+    type Error = nvngx_sys::Error;
+
+    fn try_from(value: NVSDK_NGX_DLSS_Hint_Render_Preset) -> Result<Self, Self::Error> {
+        Ok(match value {
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_Default => {
+                Self::Default
+            }
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_F => Self::F,
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_G => Self::G,
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_J => Self::J,
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_K => Self::K,
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_L => Self::L,
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_M => Self::M,
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_N => Self::N,
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_O => Self::O,
+            value => {
+                return Err(nvngx_sys::Error::Other(format!(
+                    "Unknown render preset: {value:?}"
+                )))
+            }
+        })
     }
 }
 
 /// Create parameters for the SuperSampling feature.
-#[repr(transparent)]
 #[derive(Debug)]
-pub struct SuperSamplingCreateParameters(pub(crate) nvngx_sys::NVSDK_NGX_DLSS_Create_Params);
+pub struct SuperSamplingCreateParameters {
+    /// The parameters passed to the NGX.
+    pub(crate) parameters: nvngx_sys::NVSDK_NGX_DLSS_Create_Params,
+    /// The dynamic resolution range, if the dynamic resolution is
+    /// enabled.
+    pub(crate) dynamic_resolution_range: Option<DynamicResolutionRange>,
+}
 
 impl SuperSamplingCreateParameters {
     /// Creates a new set of create parameters for the SuperSampling
@@ -98,8 +637,8 @@ impl SuperSamplingCreateParameters {
         render_height: u32,
         target_width: u32,
         target_height: u32,
-        quality_value: Option<NVSDK_NGX_PerfQuality_Value>,
-        flags: Option<NVSDK_NGX_DLSS_Feature_Flags>,
+        quality_value: Option<QualityMode>,
+        flags: DlssFeatureFlags,
     ) -> Self {
         let mut params: NVSDK_NGX_DLSS_Create_Params = unsafe { std::mem::zeroed() };
         params.Feature.InWidth = render_width;
@@ -107,27 +646,90 @@ impl SuperSamplingCreateParameters {
         params.Feature.InTargetWidth = target_width;
         params.Feature.InTargetHeight = target_height;
         if let Some(quality_value) = quality_value {
-            params.Feature.InPerfQualityValue = quality_value;
+            params.Feature.InPerfQualityValue = quality_value.into();
+        }
+        params.InFeatureCreateFlags = flags.0;
+        Self {
+            parameters: params,
+            dynamic_resolution_range: None,
         }
-        params.InFeatureCreateFlags = flags.map(|f| f.0).unwrap_or(0);
-        Self(params)
     }
-}
 
-impl From<SuperSamplingOptimalSettings> for SuperSamplingCreateParameters {
-    fn from(value: SuperSamplingOptimalSettings) -> Self {
+    /// Creates a new set of create parameters for the DLAA (Deep
+    /// Learning Anti-Aliasing): the SuperSampling feature with the
+    /// render resolution equal to the target `resolution`.
+    pub fn new_dlaa(resolution: vk::Extent2D, flags: DlssFeatureFlags) -> Self {
         Self::new(
-            value.render_width,
-            value.render_height,
-            value.target_width,
-            value.target_height,
-            Some(value.desired_quality_level),
-            Some(
-                NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AutoExposure
-                    | NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_MVLowRes,
-            ),
+            resolution.width,
+            resolution.height,
+            resolution.width,
+            resolution.height,
+            Some(QualityMode::Dlaa),
+            flags,
         )
     }
+
+    /// Returns the quality level. Fails if the quality value isn't a
+    /// known one.
+    pub fn get_quality_level(&self) -> Result<QualityMode> {
+        QualityMode::try_from(self.parameters.Feature.InPerfQualityValue)
+    }
+
+    /// Creates a new set of create parameters for the SuperSampling
+    /// feature from the optimal settings and the `flags` provided.
+    pub fn from_optimal_settings(
+        settings: SuperSamplingOptimalSettings,
+        flags: DlssFeatureFlags,
+    ) -> Self {
+        Self::new(
+            settings.render_width,
+            settings.render_height,
+            settings.target_width,
+            settings.target_height,
+            Some(settings.desired_quality_level),
+            flags,
+        )
+    }
+
+    /// Returns the feature creation flags.
+    pub fn get_flags(&self) -> DlssFeatureFlags {
+        DlssFeatureFlags(self.parameters.InFeatureCreateFlags)
+    }
+
+    /// Enables or disables the output subrects, so that the upscaled
+    /// image may be written to a region of a bigger output resource,
+    /// starting at the
+    /// [`SuperSamplingEvaluationParameters::set_output_subrect_base`].
+    pub fn with_output_subrects(mut self, enabled: bool) -> Self {
+        self.parameters.InEnableOutputSubrects = enabled;
+        self
+    }
+
+    /// Returns `true` if the output subrects are enabled.
+    pub fn is_output_subrects_enabled(&self) -> bool {
+        self.parameters.InEnableOutputSubrects
+    }
+
+    /// Enables the dynamic resolution: the feature is created for the
+    /// maximal render resolution of the `range`, and the render
+    /// resolution may then be changed every frame within the `range`
+    /// using [`SuperSamplingFeature::set_render_size`], without
+    /// recreating the feature.
+    ///
+    /// The `range` is usually obtained via
+    /// [`SuperSamplingOptimalSettings::get_dynamic_resolution_range`].
+    pub fn with_dynamic_resolution(mut self, range: DynamicResolutionRange) -> Self {
+        self.parameters.Feature.InWidth = range.max.width;
+        self.parameters.Feature.InHeight = range.max.height;
+        self.dynamic_resolution_range = Some(range);
+        self
+    }
+
+    /// Returns the dynamic resolution range, if the dynamic
+    /// resolution is enabled.
+    pub fn get_dynamic_resolution_range(&self) -> Option<DynamicResolutionRange> {
+        self.dynamic_resolution_range
+    }
 }
 
 // /// Only mandatory parameters for the SuperSampling feature evaluation.
@@ -172,12 +774,32 @@ pub struct SuperSamplingEvaluationParameters {
     depth_resource: NVSDK_NGX_Resource_VK,
     /// The motion vectors.
     motion_vectors_resource: NVSDK_NGX_Resource_VK,
+    /// The transparency mask.
+    transparency_mask_resource: NVSDK_NGX_Resource_VK,
+    /// The exposure texture (1x1).
+    exposure_texture_resource: NVSDK_NGX_Resource_VK,
+    /// The bias current color mask.
+    bias_current_color_mask_resource: NVSDK_NGX_Resource_VK,
+    /// The 3D motion vectors.
+    motion_vectors_3d_resource: NVSDK_NGX_Resource_VK,
+    /// The particle mask.
+    particle_mask_resource: NVSDK_NGX_Resource_VK,
+    /// The animated texture mask.
+    animated_texture_mask_resource: NVSDK_NGX_Resource_VK,
+    /// The high resolution depth buffer.
+    depth_high_res_resource: NVSDK_NGX_Resource_VK,
+    /// The view space positions.
+    position_view_space_resource: NVSDK_NGX_Resource_VK,
+    /// The ray tracing hit distance.
+    ray_tracing_hit_distance_resource: NVSDK_NGX_Resource_VK,
+    /// The motion vectors of the reflections.
+    motion_vectors_reflections_resource: NVSDK_NGX_Resource_VK,
 
     /// This member isn't visible, as it shouldn't be managed by
     /// the user of this struct. Instead, this struct provides an
     /// interface that populates this object and keeps it well-
     /// maintained.
-    parameters: NVSDK_NGX_VK_DLSS_Eval_Params,
+    pub(crate) parameters: NVSDK_NGX_VK_DLSS_Eval_Params,
 }
 
 impl Default for SuperSamplingEvaluationParameters {
@@ -192,81 +814,238 @@ impl SuperSamplingEvaluationParameters {
         Self::default()
     }
 
-    /// Sets the color input parameter (the image to upscale).
-    pub fn set_color_input(&mut self, description: VkImageResourceDescription) {
-        self.input_color_resource = description.into();
-        self.parameters.Feature.pInColor = std::ptr::addr_of_mut!(self.input_color_resource);
+    /// Sets the transparency mask: the regions of the color input
+    /// containing transparent objects (marked as `1.0`).
+    pub fn set_transparency_mask(&mut self, description: VkImageResourceDescription) {
+        self.transparency_mask_resource = description.into();
+        self.parameters.pInTransparencyMask =
+            std::ptr::addr_of_mut!(self.transparency_mask_resource);
     }
 
-    /// Sets the color output (the upscaled image) information.
-    pub fn set_color_output(&mut self, description: VkImageResourceDescription) {
-        self.output_color_resource = description.into();
-        self.parameters.Feature.pInOutput = std::ptr::addr_of_mut!(self.output_color_resource);
+    /// Sets the exposure texture: a 1x1 texture containing the
+    /// exposure the color input was rendered with. Used unless the
+    /// [`DlssFeatureFlags::auto_exposure`] is enabled.
+    pub fn set_exposure_texture(&mut self, description: VkImageResourceDescription) {
+        self.exposure_texture_resource = description.into();
+        self.parameters.pInExposureTexture = std::ptr::addr_of_mut!(self.exposure_texture_resource);
     }
 
-    /// Sets the motion vectors.
-    /// In case the `scale` argument is omitted, the `1.0f32` scaling is
-    /// used.
-    pub fn set_motions_vectors(
-        &mut self,
-        description: VkImageResourceDescription,
-        scale: Option<[f32; 2]>,
-    ) {
-        // 1.0f32 means no scaling (they are already in the pixel space).
-        const DEFAULT_SCALING: [f32; 2] = [1.0f32, 1.0f32];
+    /// Sets the bias current color mask: the regions of the color
+    /// input (marked as `1.0`) where the current frame should be
+    /// trusted more than the history, for example, for the particles.
+    pub fn set_bias_current_color_mask(&mut self, description: VkImageResourceDescription) {
+        self.bias_current_color_mask_resource = description.into();
+        self.parameters.pInBiasCurrentColorMask =
+            std::ptr::addr_of_mut!(self.bias_current_color_mask_resource);
+    }
 
-        self.motion_vectors_resource = description.into();
-        let scales = scale.unwrap_or(DEFAULT_SCALING);
-        self.parameters.pInMotionVectors = std::ptr::addr_of_mut!(self.motion_vectors_resource);
-        self.parameters.InMVScaleX = scales[0];
-        self.parameters.InMVScaleY = scales[1];
+    /// Sets the pre-exposure value: the value the color input has
+    /// already been multiplied by. The NGX divides the input by it and
+    /// multiplies the output by it back. `0.0` means `1.0`.
+    pub fn set_pre_exposure(&mut self, pre_exposure: f32) {
+        self.parameters.InPreExposure = pre_exposure;
     }
 
-    /// Sets the depth buffer.
-    pub fn set_depth_buffer(&mut self, description: VkImageResourceDescription) {
-        self.depth_resource = description.into();
-        self.parameters.pInDepth = std::ptr::addr_of_mut!(self.depth_resource);
+    /// Sets the exposure scale: the value the exposure (from the
+    /// exposure texture or the auto-exposure) is multiplied by. `0.0`
+    /// means `1.0`.
+    pub fn set_exposure_scale(&mut self, exposure_scale: f32) {
+        self.parameters.InExposureScale = exposure_scale;
     }
 
-    /// Sets the jitter offsets (like TAA).
-    pub fn set_jitter_offsets(&mut self, x: f32, y: f32) {
-        self.parameters.InJitterOffsetX = x;
-        self.parameters.InJitterOffsetY = y;
+    /// Sets whether the on-screen debug indicator should be flipped
+    /// along the X and Y axes.
+    pub fn set_indicator_invert_axes(&mut self, invert_x: bool, invert_y: bool) {
+        self.parameters.InIndicatorInvertXAxis = invert_x as _;
+        self.parameters.InIndicatorInvertYAxis = invert_y as _;
     }
 
-    /// Sets/unsets the reset flag.
-    pub fn set_reset(&mut self, should_reset: bool) {
-        self.parameters.InReset = if should_reset { 1 } else { 0 };
+    /// Sets the type of the tone mapper the color output is going to
+    /// be passed to.
+    pub fn set_tone_mapper_type(&mut self, tone_mapper_type: ToneMapperType) {
+        self.parameters.InToneMapperType = tone_mapper_type.into();
     }
 
-    /// Sets the rendering dimensions.
-    pub fn set_rendering_dimensions(
-        &mut self,
-        rendering_offset: [u32; 2],
-        rendering_size: [u32; 2],
-    ) {
+    /// Sets the 3D motion vectors.
+    pub fn set_motion_vectors_3d(&mut self, description: VkImageResourceDescription) {
+        self.motion_vectors_3d_resource = description.into();
+        self.parameters.pInMotionVectors3D =
+            std::ptr::addr_of_mut!(self.motion_vectors_3d_resource);
+    }
+
+    /// Sets the particle mask: the regions of the color input
+    /// containing particles (marked as `1.0`).
+    pub fn set_particle_mask(&mut self, description: VkImageResourceDescription) {
+        self.particle_mask_resource = description.into();
+        self.parameters.pInIsParticleMask = std::ptr::addr_of_mut!(self.particle_mask_resource);
+    }
+
+    /// Sets the animated texture mask: the regions of the color
+    /// input containing animated textures (marked as `1.0`).
+    pub fn set_animated_texture_mask(&mut self, description: VkImageResourceDescription) {
+        self.animated_texture_mask_resource = description.into();
+        self.parameters.pInAnimatedTextureMask =
+            std::ptr::addr_of_mut!(self.animated_texture_mask_resource);
+    }
+
+    /// Sets the depth buffer in the target (output) resolution.
+    pub fn set_depth_high_res(&mut self, description: VkImageResourceDescription) {
+        self.depth_high_res_resource = description.into();
+        self.parameters.pInDepthHighRes = std::ptr::addr_of_mut!(self.depth_high_res_resource);
+    }
+
+    /// Sets the view space positions.
+    pub fn set_position_view_space(&mut self, description: VkImageResourceDescription) {
+        self.position_view_space_resource = description.into();
+        self.parameters.pInPositionViewSpace =
+            std::ptr::addr_of_mut!(self.position_view_space_resource);
+    }
+
+    /// Sets the time passed since the previous frame, in milliseconds.
+    pub fn set_frame_time_delta(&mut self, milliseconds: f32) {
+        self.parameters.InFrameTimeDeltaInMsec = milliseconds;
+    }
+
+    /// Sets the ray tracing hit distance.
+    pub fn set_ray_tracing_hit_distance(&mut self, description: VkImageResourceDescription) {
+        self.ray_tracing_hit_distance_resource = description.into();
+        self.parameters.pInRayTracingHitDistance =
+            std::ptr::addr_of_mut!(self.ray_tracing_hit_distance_resource);
+    }
+
+    /// Sets the motion vectors of the reflections.
+    pub fn set_motion_vectors_reflections(&mut self, description: VkImageResourceDescription) {
+        self.motion_vectors_reflections_resource = description.into();
+        self.parameters.pInMotionVectorsReflections =
+            std::ptr::addr_of_mut!(self.motion_vectors_reflections_resource);
+    }
+
+    /// Sets the offset of the rendered region in the color input.
+    pub fn set_color_subrect_base(&mut self, base: [u32; 2]) {
         self.parameters.InColorSubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+            X: base[0],
+            Y: base[1],
         };
+    }
+
+    /// Sets the offset of the rendered region in the depth buffer.
+    pub fn set_depth_subrect_base(&mut self, base: [u32; 2]) {
         self.parameters.InDepthSubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+            X: base[0],
+            Y: base[1],
+        };
+    }
+
+    /// Sets the offset of the rendered region in the motion vectors.
+    pub fn set_motion_vectors_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InMVSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
         };
+    }
+
+    /// Sets the offset of the rendered region in the transparency
+    /// mask.
+    pub fn set_translucency_subrect_base(&mut self, base: [u32; 2]) {
         self.parameters.InTranslucencySubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+            X: base[0],
+            Y: base[1],
         };
-        self.parameters.InMVSubrectBase = NVSDK_NGX_Coordinates {
-            X: rendering_offset[0],
-            Y: rendering_offset[1],
+    }
+
+    /// Sets the offset of the rendered region in the bias current
+    /// color mask.
+    pub fn set_bias_current_color_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InBiasCurrentColorSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
         };
-        self.parameters.InRenderSubrectDimensions = NVSDK_NGX_Dimensions {
-            Width: rendering_size[0],
-            Height: rendering_size[1],
+    }
+
+    /// Sets the offset of the region of the color output the upscaled
+    /// image is written to. The region has the size of the target
+    /// resolution. Requires the feature to be created with the output
+    /// subrects enabled
+    /// ([`SuperSamplingCreateParameters::with_output_subrects`]).
+    pub fn set_output_subrect_base(&mut self, base: [u32; 2]) {
+        self.parameters.InOutputSubrectBase = NVSDK_NGX_Coordinates {
+            X: base[0],
+            Y: base[1],
         };
     }
 
+    /// Returns the size of the rendered region: the render subrect
+    /// dimensions if set, or the `render_size` otherwise.
+    pub(crate) fn get_render_subrect_size(&self, render_size: vk::Extent2D) -> vk::Extent2D {
+        let dimensions = self.parameters.InRenderSubrectDimensions;
+        if dimensions.Width != 0 && dimensions.Height != 0 {
+            vk::Extent2D::default()
+                .width(dimensions.Width)
+                .height(dimensions.Height)
+        } else {
+            render_size
+        }
+    }
+
+    /// Checks that all the subrects fit into their resources. The
+    /// `render_size` is used for the inputs in the render
+    /// resolution, unless the render subrect dimensions are set.
+    pub(crate) fn validate_subrects(
+        &self,
+        render_size: vk::Extent2D,
+        target_size: vk::Extent2D,
+        flags: DlssFeatureFlags,
+        output_subrects_enabled: bool,
+    ) -> Result {
+        let p = &self.parameters;
+        let render_size = self.get_render_subrect_size(render_size);
+        let motion_vectors_size = if flags.is_mv_low_res() {
+            render_size
+        } else {
+            target_size
+        };
+
+        if !output_subrects_enabled
+            && (p.InOutputSubrectBase.X != 0 || p.InOutputSubrectBase.Y != 0)
+        {
+            return Err(nvngx_sys::Error::Other(
+                "The output subrect base is set, but the feature was created without the output subrects enabled.".to_owned(),
+            ));
+        }
+
+        check_subrect_fits(
+            "color input",
+            p.Feature.pInColor,
+            p.InColorSubrectBase,
+            render_size,
+        )?;
+        check_subrect_fits("depth", p.pInDepth, p.InDepthSubrectBase, render_size)?;
+        check_subrect_fits(
+            "motion vectors",
+            p.pInMotionVectors,
+            p.InMVSubrectBase,
+            motion_vectors_size,
+        )?;
+        check_subrect_fits(
+            "transparency mask",
+            p.pInTransparencyMask,
+            p.InTranslucencySubrectBase,
+            render_size,
+        )?;
+        check_subrect_fits(
+            "bias current color mask",
+            p.pInBiasCurrentColorMask,
+            p.InBiasCurrentColorSubrectBase,
+            render_size,
+        )?;
+        check_subrect_fits(
+            "color output",
+            p.Feature.pInOutput,
+            p.InOutputSubrectBase,
+            target_size,
+        )
+    }
+
     /// Returns the filled DLSS parameters.
     pub(crate) fn get_dlss_evaluation_parameters(
         &mut self,
@@ -295,6 +1074,46 @@ impl SuperSamplingEvaluationParameters {
     // }
 }
 
+impl temporal_upscaler::private::Sealed for SuperSamplingEvaluationParameters {
+    fn get_common_fields(&mut self) -> temporal_upscaler::private::CommonEvaluationFields<'_> {
+        let p = &mut self.parameters;
+        temporal_upscaler::private::CommonEvaluationFields {
+            color_input: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.input_color_resource,
+                pointer: &mut p.Feature.pInColor,
+            },
+            color_output: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.output_color_resource,
+                pointer: &mut p.Feature.pInOutput,
+            },
+            depth: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.depth_resource,
+                pointer: &mut p.pInDepth,
+            },
+            motion_vectors: temporal_upscaler::private::ResourceSlot {
+                resource: &mut self.motion_vectors_resource,
+                pointer: &mut p.pInMotionVectors,
+            },
+            motion_vectors_scale: [&mut p.InMVScaleX, &mut p.InMVScaleY],
+            jitter_offset: [&mut p.InJitterOffsetX, &mut p.InJitterOffsetY],
+            reset: &mut p.InReset,
+            render_subrect_dimensions: &mut p.InRenderSubrectDimensions,
+        }
+    }
+
+    /// Sets the offsets of the color, depth, translucency, motion
+    /// vectors and bias current color mask.
+    fn set_all_subrect_bases(&mut self, base: NVSDK_NGX_Coordinates) {
+        self.parameters.InColorSubrectBase = base;
+        self.parameters.InDepthSubrectBase = base;
+        self.parameters.InTranslucencySubrectBase = base;
+        self.parameters.InMVSubrectBase = base;
+        self.parameters.InBiasCurrentColorSubrectBase = base;
+    }
+}
+
+impl TemporalUpscalerInputs for SuperSamplingEvaluationParameters {}
+
 /// A SuperSamling (or "DLSS") feature.
 #[derive(Debug)]
 pub struct SuperSamplingFeature {
@@ -302,14 +1121,53 @@ pub struct SuperSamplingFeature {
     parameters: SuperSamplingEvaluationParameters,
     rendering_resolution: vk::Extent2D,
     target_resolution: vk::Extent2D,
+    quality_level: QualityMode,
+    flags: DlssFeatureFlags,
+    output_subrects_enabled: bool,
+    dynamic_resolution_range: Option<DynamicResolutionRange>,
+    render_size: vk::Extent2D,
+    motion_vector_convention: Option<MotionVectorConvention>,
+    validation_enabled: bool,
 }
 
 impl SuperSamplingFeature {
-    /// Creates a new Super Sampling feature.
+    /// Creates a new DLAA (Deep Learning Anti-Aliasing) feature: a
+    /// Super Sampling feature which only anti-aliases the image of the
+    /// `resolution`, without upscaling it. The feature is evaluated the
+    /// same way the upscaling one is, including the jitter
+    /// ([`JitterSequence`]).
+    ///
+    /// The `render_preset` is set as the DLAA render preset hint
+    /// ([`nvngx_sys::NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_DLAA`])
+    /// of the `feature_parameters`; [`None`] lets the NGX choose.
+    pub fn new_dlaa(
+        system: &System,
+        command_buffer: vk::CommandBuffer,
+        feature_parameters: FeatureParameters,
+        resolution: vk::Extent2D,
+        flags: DlssFeatureFlags,
+        render_preset: Option<RenderPreset>,
+    ) -> Result<Self> {
+        let render_preset =
+            NVSDK_NGX_DLSS_Hint_Render_Preset::from(render_preset.unwrap_or_default());
+        feature_parameters.set_u32(
+            nvngx_sys::NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_DLAA,
+            render_preset as u32,
+        );
+        system.create_super_sampling_feature(
+            command_buffer,
+            feature_parameters,
+            SuperSamplingCreateParameters::new_dlaa(resolution, flags),
+        )
+    }
+
+    /// Creates a new Super Sampling feature from the `feature` created
+    /// with the `create_parameters`, which the resolutions, the quality
+    /// level, the flags, the output subrects and the dynamic resolution
+    /// are taken from.
     pub fn new(
         feature: Feature,
-        rendering_resolution: vk::Extent2D,
-        target_resolution: vk::Extent2D,
+        create_parameters: &SuperSamplingCreateParameters,
     ) -> Result<Self> {
         if !feature.is_super_sampling() {
             return Err(nvngx_sys::Error::Other(
@@ -317,11 +1175,23 @@ impl SuperSamplingFeature {
             ));
         }
 
+        let rendering_resolution = vk::Extent2D::default()
+            .width(create_parameters.parameters.Feature.InWidth)
+            .height(create_parameters.parameters.Feature.InHeight);
         Ok(Self {
             feature,
             parameters: SuperSamplingEvaluationParameters::new(),
             rendering_resolution,
-            target_resolution,
+            target_resolution: vk::Extent2D::default()
+                .width(create_parameters.parameters.Feature.InTargetWidth)
+                .height(create_parameters.parameters.Feature.InTargetHeight),
+            quality_level: create_parameters.get_quality_level()?,
+            flags: create_parameters.get_flags(),
+            output_subrects_enabled: create_parameters.is_output_subrects_enabled(),
+            dynamic_resolution_range: create_parameters.get_dynamic_resolution_range(),
+            render_size: rendering_resolution,
+            motion_vector_convention: None,
+            validation_enabled: false,
         })
     }
 
@@ -341,12 +1211,30 @@ impl SuperSamplingFeature {
         self.rendering_resolution
     }
 
+    /// Returns the quality level the feature was created with.
+    pub const fn get_quality_level(&self) -> QualityMode {
+        self.quality_level
+    }
+
+    /// Returns [`true`] if the feature only anti-aliases the image:
+    /// it was created with the [`QualityMode::Dlaa`] quality level.
+    pub fn is_dlaa(&self) -> bool {
+        self.quality_level == QualityMode::Dlaa
+    }
+
     /// Returns the target resolution (output resolution) of the
     /// image that the original image should be upscaled to.
     pub const fn get_target_resolution(&self) -> vk::Extent2D {
         self.target_resolution
     }
 
+    /// Returns the texture LOD bias recommended for the render
+    /// resolution of the current frame ([`Self::get_render_size`]).
+    /// See [`get_recommended_lod_bias`] and [`apply_lod_bias`].
+    pub fn get_recommended_lod_bias(&self) -> f32 {
+        get_recommended_lod_bias(self.render_size, self.target_resolution)
+    }
+
     // /// Attempts to create the [`SuperSamplingFeature`] with the default
     // /// settings preset.
     // pub fn try_default() -> Result<Self> {
@@ -366,8 +1254,133 @@ impl SuperSamplingFeature {
         &mut self.parameters
     }
 
+    /// Returns the range the render resolution may be changed within,
+    /// if the feature was created with the dynamic resolution enabled
+    /// ([`SuperSamplingCreateParameters::with_dynamic_resolution`]).
+    pub fn get_dynamic_resolution_range(&self) -> Option<DynamicResolutionRange> {
+        self.dynamic_resolution_range
+    }
+
+    /// Returns the render resolution of the current frame: the one
+    /// set via [`Self::set_render_size`], or the rendering resolution
+    /// the feature was created with.
+    pub const fn get_render_size(&self) -> vk::Extent2D {
+        self.render_size
+    }
+
+    /// Sets the render resolution of the current (and the following)
+    /// frames, without recreating the feature. The rendered region of
+    /// the inputs is adjusted accordingly, and so is the motion
+    /// vectors scale, if [`Self::set_motion_vector_convention`] is
+    /// used.
+    ///
+    /// Fails if the feature was created without the dynamic
+    /// resolution enabled, or the `render_size` is outside of the
+    /// [`Self::get_dynamic_resolution_range`].
+    pub fn set_render_size(&mut self, render_size: vk::Extent2D) -> Result {
+        let Some(range) = self.dynamic_resolution_range else {
+            return Err(nvngx_sys::Error::Other(
+                "The feature was created without the dynamic resolution enabled.".to_owned(),
+            ));
+        };
+        if !range.contains(render_size) {
+            return Err(nvngx_sys::Error::Other(format!(
+                "The render size {}x{} is outside of the dynamic resolution range from {}x{} to {}x{}.",
+                render_size.width,
+                render_size.height,
+                range.min.width,
+                range.min.height,
+                range.max.width,
+                range.max.height,
+            )));
+        }
+
+        self.render_size = render_size;
+        self.parameters
+            .set_render_subrect_dimensions([render_size.width, render_size.height]);
+        Ok(())
+    }
+
+    /// Sets the convention the motion vectors are produced in, so
+    /// that their scale is computed at every evaluation from the
+    /// current render size and the target resolution
+    /// ([`MotionVectorConvention::get_scale`]), overriding the scale
+    /// set via [`TemporalUpscalerInputs::set_motions_vectors`].
+    ///
+    /// Fails if the flags the feature was created with don't match the
+    /// convention ([`MotionVectorConvention::apply_to_flags`]).
+    pub fn set_motion_vector_convention(
+        &mut self,
+        convention: Option<MotionVectorConvention>,
+    ) -> Result {
+        if let Some(convention) = convention {
+            convention.check_flags(self.flags)?;
+        }
+        self.motion_vector_convention = convention;
+        Ok(())
+    }
+
+    /// Returns the convention the motion vectors are produced in, if
+    /// set.
+    pub fn get_motion_vector_convention(&self) -> Option<MotionVectorConvention> {
+        self.motion_vector_convention
+    }
+
+    /// Returns the flags the feature was created with.
+    pub fn get_flags(&self) -> DlssFeatureFlags {
+        self.flags
+    }
+
+    /// Enables or disables the validation of the evaluation inputs
+    /// before every [`Self::evaluate`], disabled by default. See the
+    /// [`validation`] module for what is checked.
+    pub fn set_validation_enabled(&mut self, enabled: bool) {
+        self.validation_enabled = enabled;
+    }
+
+    /// Returns [`true`] if the validation of the evaluation inputs is
+    /// enabled.
+    pub fn is_validation_enabled(&self) -> bool {
+        self.validation_enabled
+    }
+
     /// Evaluates the feature.
+    ///
+    /// Fails without recording anything if any of the subrects doesn't
+    /// fit into its resource, the color formats don't have the alpha
+    /// channel while the alpha upscaling is enabled
+    /// ([`DlssFeatureFlags::alpha_upscaling`]), or the validation fails
+    /// ([`Self::set_validation_enabled`]).
     pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        if let Some(convention) = self.motion_vector_convention {
+            let [x, y] = convention.get_scale(self.render_size, self.target_resolution);
+            self.parameters.parameters.InMVScaleX = x;
+            self.parameters.parameters.InMVScaleY = y;
+        }
+        if self.flags.is_alpha_upscaling() {
+            let p = &self.parameters.parameters;
+            validation::validate_alpha_formats(p.Feature.pInColor, p.Feature.pInOutput)?;
+        }
+        if self.validation_enabled {
+            let p = &self.parameters.parameters;
+            validation::validate_common_inputs(
+                p.Feature.pInColor,
+                p.Feature.pInOutput,
+                p.pInDepth,
+                p.pInMotionVectors,
+                self.parameters
+                    .get_render_subrect_size(self.rendering_resolution),
+                self.target_resolution,
+                self.flags,
+                self.output_subrects_enabled,
+            )?;
+        }
+        self.parameters.validate_subrects(
+            self.rendering_resolution,
+            self.target_resolution,
+            self.flags,
+            self.output_subrects_enabled,
+        )?;
         Result::from(unsafe {
             nvngx_sys::HELPERS_NGX_VULKAN_EVALUATE_DLSS_EXT(
                 command_buffer,
@@ -378,3 +1391,183 @@ impl SuperSamplingFeature {
         })
     }
 }
+
+impl TemporalUpscaler for SuperSamplingFeature {
+    fn get_inputs_mut(&mut self) -> &mut dyn TemporalUpscalerInputs {
+        &mut self.parameters
+    }
+
+    fn get_rendering_resolution(&self) -> vk::Extent2D {
+        self.rendering_resolution
+    }
+
+    fn get_target_resolution(&self) -> vk::Extent2D {
+        self.target_resolution
+    }
+
+    fn get_render_size(&self) -> vk::Extent2D {
+        self.render_size
+    }
+
+    fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        SuperSamplingFeature::evaluate(self, command_buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DlssFeatureFlags, DynamicResolutionRange, RenderPreset, SuperSamplingCreateParameters,
+        SuperSamplingEvaluationParameters, SuperSamplingOptimalSettings,
+    };
+    use crate::QualityMode;
+    use crate::TemporalUpscalerInputs;
+    use crate::VkImageResourceDescription;
+    use ash::vk;
+    use nvngx_sys::{NVSDK_NGX_DLSS_Feature_Flags, NVSDK_NGX_DLSS_Hint_Render_Preset};
+
+    #[test]
+    fn feature_flags_builder() {
+        let flags = DlssFeatureFlags::new()
+            .hdr(true)
+            .mv_low_res(true)
+            .depth_inverted(true)
+            .mv_low_res(false);
+        assert!(flags.is_hdr());
+        assert!(flags.is_depth_inverted());
+        assert!(!flags.is_mv_low_res());
+        assert!(!flags.is_auto_exposure());
+        assert_eq!(
+            NVSDK_NGX_DLSS_Feature_Flags::from(flags),
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_IsHDR
+                | NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_DepthInverted
+        );
+    }
+
+    #[test]
+    fn feature_flags_reject_invalid() {
+        assert!(DlssFeatureFlags::try_from(
+            NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_IsInvalid
+                | NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_IsHDR
+        )
+        .is_err());
+        assert_eq!(
+            DlssFeatureFlags::try_from(
+                NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AutoExposure
+            )
+            .unwrap(),
+            DlssFeatureFlags::new().auto_exposure(true)
+        );
+    }
+
+    #[test]
+    fn subrects_must_fit_into_resources() {
+        let image = |width, height| VkImageResourceDescription {
+            width,
+            height,
+            ..Default::default()
+        };
+        let render_size = vk::Extent2D::default().width(64).height(64);
+        let target_size = vk::Extent2D::default().width(128).height(128);
+        let flags = DlssFeatureFlags::new().mv_low_res(true);
+
+        let mut parameters = SuperSamplingEvaluationParameters::new();
+        parameters.set_color_input(image(128, 128));
+        parameters.set_depth_buffer(image(128, 128));
+        parameters.set_color_output(image(128, 128));
+        parameters.set_color_subrect_base([64, 64]);
+        parameters.set_depth_subrect_base([32, 0]);
+        assert!(parameters
+            .validate_subrects(render_size, target_size, flags, false)
+            .is_ok());
+
+        parameters.set_depth_subrect_base([65, 0]);
+        assert!(parameters
+            .validate_subrects(render_size, target_size, flags, false)
+            .is_err());
+
+        parameters.set_depth_subrect_base([0, 0]);
+        parameters.set_output_subrect_base([1, 0]);
+        assert!(parameters
+            .validate_subrects(render_size, target_size, flags, false)
+            .is_err());
+        parameters.set_color_output(image(256, 128));
+        assert!(parameters
+            .validate_subrects(render_size, target_size, flags, true)
+            .is_ok());
+    }
+
+    #[test]
+    fn dynamic_resolution_range_contains() {
+        let range = DynamicResolutionRange {
+            min: vk::Extent2D::default().width(640).height(360),
+            max: vk::Extent2D::default().width(1280).height(720),
+        };
+        assert!(range.contains(range.min));
+        assert!(range.contains(range.max));
+        assert!(range.contains(vk::Extent2D::default().width(960).height(540)));
+        assert!(!range.contains(vk::Extent2D::default().width(1281).height(720)));
+        assert!(!range.contains(vk::Extent2D::default().width(960).height(300)));
+    }
+
+    #[test]
+    fn scale_factor_of_unsupported_settings() {
+        let mut settings = SuperSamplingOptimalSettings {
+            render_width: 1280,
+            render_height: 720,
+            target_width: 1920,
+            target_height: 1080,
+            desired_quality_level: QualityMode::Quality,
+            dynamic_min_render_width: 1280,
+            dynamic_max_render_width: 1280,
+            dynamic_min_render_height: 720,
+            dynamic_max_render_height: 720,
+        };
+        assert_eq!(settings.get_scale_factor(), Some(1.5));
+
+        settings.render_width = 0;
+        settings.render_height = 0;
+        assert_eq!(settings.get_scale_factor(), None);
+    }
+
+    #[test]
+    fn dlaa_create_parameters() {
+        let resolution = vk::Extent2D::default().width(1920).height(1080);
+        let parameters =
+            SuperSamplingCreateParameters::new_dlaa(resolution, DlssFeatureFlags::new());
+        assert_eq!(parameters.get_quality_level().unwrap(), QualityMode::Dlaa);
+        assert_eq!(
+            parameters.parameters.Feature.InWidth,
+            parameters.parameters.Feature.InTargetWidth
+        );
+        assert_eq!(
+            parameters.parameters.Feature.InHeight,
+            parameters.parameters.Feature.InTargetHeight
+        );
+
+        let parameters = SuperSamplingCreateParameters::new(
+            960,
+            540,
+            1920,
+            1080,
+            Some(QualityMode::Performance),
+            DlssFeatureFlags::new(),
+        );
+        assert_eq!(
+            parameters.get_quality_level().unwrap(),
+            QualityMode::Performance
+        );
+    }
+
+    #[test]
+    fn render_preset_conversion() {
+        for preset in [RenderPreset::Default, RenderPreset::F, RenderPreset::O] {
+            let value = NVSDK_NGX_DLSS_Hint_Render_Preset::from(preset);
+            assert_eq!(RenderPreset::try_from(value).unwrap(), preset);
+        }
+        assert!(RenderPreset::try_from(
+            NVSDK_NGX_DLSS_Hint_Render_Preset::NVSDK_NGX_DLSS_Hint_Render_Preset_H_Reserved
+        )
+        .is_err());
+    }
+}
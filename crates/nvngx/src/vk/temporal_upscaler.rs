@@ -0,0 +1,208 @@
+//! The interface shared by the temporal upscalers: the SuperSampling
+//! (DLSS-SR) and the Ray Reconstruction (DLSS-RR), so that the same
+//! renderer code may drive either of them.
+
+use super::*;
+
+pub(crate) mod private {
+    use nvngx_sys::{NVSDK_NGX_Coordinates, NVSDK_NGX_Dimensions, NVSDK_NGX_Resource_VK};
+
+    use crate::VkImageResourceDescription;
+
+    /// A resource stored along with the evaluation parameters, and the
+    /// pointer to it the NGX reads.
+    #[derive(Debug)]
+    pub struct ResourceSlot<'a> {
+        /// The resource.
+        pub resource: &'a mut NVSDK_NGX_Resource_VK,
+        /// The pointer in the evaluation parameters.
+        pub pointer: &'a mut *mut NVSDK_NGX_Resource_VK,
+    }
+
+    impl ResourceSlot<'_> {
+        /// Stores the `description` and points the pointer to it.
+        pub fn set(self, description: VkImageResourceDescription) {
+            *self.resource = description.into();
+            *self.pointer = self.resource;
+        }
+    }
+
+    /// The fields of the evaluation parameters every temporal upscaler
+    /// has.
+    #[derive(Debug)]
+    pub struct CommonEvaluationFields<'a> {
+        /// The color input.
+        pub color_input: ResourceSlot<'a>,
+        /// The color output.
+        pub color_output: ResourceSlot<'a>,
+        /// The depth buffer.
+        pub depth: ResourceSlot<'a>,
+        /// The motion vectors.
+        pub motion_vectors: ResourceSlot<'a>,
+        /// The `InMVScaleX` and `InMVScaleY`.
+        pub motion_vectors_scale: [&'a mut f32; 2],
+        /// The `InJitterOffsetX` and `InJitterOffsetY`.
+        pub jitter_offset: [&'a mut f32; 2],
+        /// The `InReset`.
+        pub reset: &'a mut std::os::raw::c_int,
+        /// The `InRenderSubrectDimensions`.
+        pub render_subrect_dimensions: &'a mut NVSDK_NGX_Dimensions,
+    }
+
+    /// Gives the [`super::TemporalUpscalerInputs`] access to the
+    /// evaluation parameters, and prevents implementing it outside of
+    /// the crate.
+    pub trait Sealed {
+        /// Returns the fields every temporal upscaler has.
+        fn get_common_fields(&mut self) -> CommonEvaluationFields<'_>;
+
+        /// Sets the offset of the rendered region in every input in
+        /// the render resolution.
+        fn set_all_subrect_bases(&mut self, base: NVSDK_NGX_Coordinates);
+    }
+}
+
+/// The inputs every temporal upscaler requires, implemented by the
+/// [`SuperSamplingEvaluationParameters`] and the
+/// [`RayReconstructionEvaluationParameters`].
+///
+/// The trait is object safe, so the renderer may set the inputs of
+/// whichever feature is in use at runtime via
+/// [`TemporalUpscaler::get_inputs_mut`].
+pub trait TemporalUpscalerInputs: private::Sealed {
+    /// Sets the color input parameter (the image to upscale).
+    fn set_color_input(&mut self, description: VkImageResourceDescription) {
+        self.get_common_fields().color_input.set(description);
+    }
+
+    /// Sets the color output (the upscaled image) information.
+    fn set_color_output(&mut self, description: VkImageResourceDescription) {
+        self.get_common_fields().color_output.set(description);
+    }
+
+    /// Sets the motion vectors.
+    /// In case the `scale` argument is omitted, the `1.0f32` scaling is
+    /// used.
+    fn set_motions_vectors(
+        &mut self,
+        description: VkImageResourceDescription,
+        scale: Option<[f32; 2]>,
+    ) {
+        // 1.0f32 means no scaling (they are already in the pixel space).
+        const DEFAULT_SCALING: [f32; 2] = [1.0f32, 1.0f32];
+
+        let fields = self.get_common_fields();
+        fields.motion_vectors.set(description);
+        let scales = scale.unwrap_or(DEFAULT_SCALING);
+        *fields.motion_vectors_scale[0] = scales[0];
+        *fields.motion_vectors_scale[1] = scales[1];
+    }
+
+    /// Sets the depth buffer.
+    fn set_depth_buffer(&mut self, description: VkImageResourceDescription) {
+        self.get_common_fields().depth.set(description);
+    }
+
+    /// Sets the jitter offsets (like TAA).
+    fn set_jitter_offsets(&mut self, x: f32, y: f32) {
+        let fields = self.get_common_fields();
+        *fields.jitter_offset[0] = x;
+        *fields.jitter_offset[1] = y;
+    }
+
+    /// Sets/unsets the reset flag.
+    fn set_reset(&mut self, should_reset: bool) {
+        *self.get_common_fields().reset = if should_reset { 1 } else { 0 };
+    }
+
+    /// Sets the size of the rendered region (of all the inputs in the
+    /// render resolution), leaving the offsets intact.
+    fn set_render_subrect_dimensions(&mut self, size: [u32; 2]) {
+        *self.get_common_fields().render_subrect_dimensions = NVSDK_NGX_Dimensions {
+            Width: size[0],
+            Height: size[1],
+        };
+    }
+
+    /// Sets the rendering dimensions: the size of the rendered region
+    /// and its offset, used for all the inputs in the render
+    /// resolution. Use the per-input setters of the evaluation
+    /// parameters when the inputs are at different offsets.
+    fn set_rendering_dimensions(&mut self, rendering_offset: [u32; 2], rendering_size: [u32; 2]) {
+        self.set_all_subrect_bases(NVSDK_NGX_Coordinates {
+            X: rendering_offset[0],
+            Y: rendering_offset[1],
+        });
+        self.set_render_subrect_dimensions(rendering_size);
+    }
+}
+
+/// A temporal upscaler feature: the [`SuperSamplingFeature`] or the
+/// [`RayReconstructionFeature`], so that the renderer may switch
+/// between them at runtime, for example, via a
+/// `Box<dyn TemporalUpscaler>`.
+pub trait TemporalUpscaler {
+    /// Returns the inputs of the next evaluation.
+    fn get_inputs_mut(&mut self) -> &mut dyn TemporalUpscalerInputs;
+
+    /// Returns the rendering resolution (input resolution) of the
+    /// image that needs to be upscaled to the target resolution.
+    fn get_rendering_resolution(&self) -> vk::Extent2D;
+
+    /// Returns the target resolution (output resolution) of the
+    /// image that the original image should be upscaled to.
+    fn get_target_resolution(&self) -> vk::Extent2D;
+
+    /// Returns the render resolution of the current frame, which may
+    /// be lower than the rendering resolution the feature was created
+    /// with when the render resolution changes dynamically.
+    fn get_render_size(&self) -> vk::Extent2D {
+        self.get_rendering_resolution()
+    }
+
+    /// Evaluates the feature.
+    fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemporalUpscalerInputs;
+    use crate::{
+        RayReconstructionEvaluationParameters, SuperSamplingEvaluationParameters,
+        VkImageResourceDescription,
+    };
+
+    #[test]
+    fn inputs_through_the_trait_object() {
+        let mut super_sampling = SuperSamplingEvaluationParameters::new();
+        let mut ray_reconstruction = RayReconstructionEvaluationParameters::new();
+        let inputs: [&mut dyn TemporalUpscalerInputs; 2] =
+            [&mut super_sampling, &mut ray_reconstruction];
+        for inputs in inputs {
+            inputs.set_color_input(VkImageResourceDescription::default());
+            inputs.set_motions_vectors(VkImageResourceDescription::default(), Some([2.0, -2.0]));
+            inputs.set_jitter_offsets(0.25, -0.25);
+            inputs.set_reset(true);
+            inputs.set_rendering_dimensions([8, 4], [64, 32]);
+        }
+
+        let p = &super_sampling.parameters;
+        assert!(!p.Feature.pInColor.is_null() && !p.pInMotionVectors.is_null());
+        assert_eq!([p.InMVScaleX, p.InMVScaleY], [2.0, -2.0]);
+        assert_eq!([p.InDepthSubrectBase.X, p.InDepthSubrectBase.Y], [8, 4]);
+        assert_eq!(
+            [
+                p.InBiasCurrentColorSubrectBase.X,
+                p.InBiasCurrentColorSubrectBase.Y
+            ],
+            [8, 4]
+        );
+
+        let p = &ray_reconstruction.parameters;
+        assert!(!p.pInColor.is_null() && p.pInOutput.is_null());
+        assert_eq!([p.InJitterOffsetX, p.InJitterOffsetY], [0.25, -0.25]);
+        assert_eq!(p.InReset, 1);
+        assert_eq!([p.InNormalsSubrectBase.X, p.InNormalsSubrectBase.Y], [8, 4]);
+        assert_eq!(p.InRenderSubrectDimensions.Width, 64);
+    }
+}
@@ -0,0 +1,174 @@
+//! A managed SuperSampling upscaler, recreating the feature when its
+//! configuration changes.
+
+use super::*;
+
+/// The desired configuration of the [`SuperSamplingUpscaler`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SuperSamplingUpscalerConfig {
+    /// The target resolution (the resolution of the output image).
+    pub target_resolution: vk::Extent2D,
+    /// The quality level, which determines the render resolution.
+    pub quality_level: QualityMode,
+    /// The feature creation flags.
+    pub flags: DlssFeatureFlags,
+    /// Whether the render resolution may be changed every frame via
+    /// [`SuperSamplingFeature::set_render_size`]. See
+    /// [`SuperSamplingCreateParameters::with_dynamic_resolution`].
+    pub dynamic_resolution: bool,
+}
+
+impl SuperSamplingUpscalerConfig {
+    /// Creates a new configuration with the dynamic resolution
+    /// disabled.
+    pub fn new(
+        target_resolution: vk::Extent2D,
+        quality_level: QualityMode,
+        flags: DlssFeatureFlags,
+    ) -> Self {
+        Self {
+            target_resolution,
+            quality_level,
+            flags,
+            dynamic_resolution: false,
+        }
+    }
+}
+
+/// A SuperSampling upscaler, which owns the [`SuperSamplingFeature`]
+/// and transparently recreates it whenever the desired
+/// [`SuperSamplingUpscalerConfig`] changes: when the window is resized,
+/// the quality level or the flags are changed.
+///
+/// Every frame, [`Self::update`] should be called with the desired
+/// configuration, then the evaluation parameters should be set, and
+/// then the [`Self::evaluate`] should be called. The render
+/// resolution to render the frame in is available via
+/// [`SuperSamplingFeature::get_render_size`] of the returned feature.
+#[derive(Debug, Default)]
+pub struct SuperSamplingUpscaler {
+    feature: Option<SuperSamplingFeature>,
+    config: Option<SuperSamplingUpscalerConfig>,
+    optimal_settings: Option<SuperSamplingOptimalSettings>,
+    reset_pending: bool,
+}
+
+impl SuperSamplingUpscaler {
+    /// Creates a new upscaler. The feature is only created on the
+    /// first [`Self::update`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns [`true`] if the feature is going to be recreated by
+    /// [`Self::update`] with the `config` provided.
+    ///
+    /// The recreation releases the current feature, so the caller
+    /// must make sure the GPU has finished the work previously
+    /// recorded with it, for example, by waiting for the device to
+    /// become idle, when this returns [`true`].
+    pub fn needs_recreation(&self, config: &SuperSamplingUpscalerConfig) -> bool {
+        self.feature.is_none() || self.config.as_ref() != Some(config)
+    }
+
+    /// Makes sure the feature matches the desired `config`, recreating
+    /// it if necessary (see [`Self::needs_recreation`]), and returns
+    /// it. The creation of the feature is recorded into the
+    /// `command_buffer`, which must be submitted before (or together
+    /// with) the evaluation.
+    ///
+    /// The first evaluation after the feature is recreated resets the
+    /// history ([`TemporalUpscalerInputs::set_reset`]).
+    pub fn update(
+        &mut self,
+        system: &System,
+        command_buffer: vk::CommandBuffer,
+        config: SuperSamplingUpscalerConfig,
+    ) -> Result<&mut SuperSamplingFeature> {
+        if self.needs_recreation(&config) {
+            // Release the previous feature first, so that both
+            // features don't exist at the same time.
+            self.feature = None;
+            self.config = None;
+            self.optimal_settings = None;
+
+            let parameters = FeatureParameters::get_capability_parameters()?;
+            let optimal_settings = SuperSamplingOptimalSettings::get_optimal_settings(
+                &parameters,
+                config.target_resolution.width,
+                config.target_resolution.height,
+                config.quality_level,
+            )?;
+            let mut create_parameters = SuperSamplingCreateParameters::from_optimal_settings(
+                optimal_settings,
+                config.flags,
+            );
+            if config.dynamic_resolution {
+                create_parameters = create_parameters
+                    .with_dynamic_resolution(optimal_settings.get_dynamic_resolution_range());
+            }
+
+            let feature = system.create_super_sampling_feature(
+                command_buffer,
+                parameters,
+                create_parameters,
+            )?;
+            self.feature = Some(feature);
+            self.config = Some(config);
+            self.optimal_settings = Some(optimal_settings);
+            self.reset_pending = true;
+        }
+
+        self.get_feature_mut()
+    }
+
+    /// Returns the current configuration, if the feature is created.
+    pub fn get_config(&self) -> Option<&SuperSamplingUpscalerConfig> {
+        self.config.as_ref()
+    }
+
+    /// Returns the optimal settings the current feature was created
+    /// with, if the feature is created.
+    pub fn get_optimal_settings(&self) -> Option<&SuperSamplingOptimalSettings> {
+        self.optimal_settings.as_ref()
+    }
+
+    /// Returns the current feature, if created.
+    pub fn get_feature(&self) -> Option<&SuperSamplingFeature> {
+        self.feature.as_ref()
+    }
+
+    /// Returns the current feature, failing if it hasn't been created
+    /// by [`Self::update`] yet.
+    pub fn get_feature_mut(&mut self) -> Result<&mut SuperSamplingFeature> {
+        self.feature.as_mut().ok_or_else(|| {
+            nvngx_sys::Error::Other("The upscaler feature hasn't been created yet.".to_owned())
+        })
+    }
+
+    /// Makes the next evaluation reset the history, for example, on a
+    /// camera cut.
+    pub fn request_reset(&mut self) {
+        self.reset_pending = true;
+    }
+
+    /// Evaluates the feature, resetting the history if the feature
+    /// has just been (re)created, [`Self::request_reset`] was called,
+    /// or the reset flag is set on the evaluation parameters
+    /// ([`TemporalUpscalerInputs::set_reset`]), which is left intact.
+    pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        let reset_pending = self.reset_pending;
+        let feature = self.get_feature_mut()?;
+        let parameters = feature.get_evaluation_parameters_mut();
+        let user_reset = parameters.parameters.InReset != 0;
+        parameters.set_reset(user_reset || reset_pending);
+        let result = feature.evaluate(command_buffer);
+        feature
+            .get_evaluation_parameters_mut()
+            .set_reset(user_reset);
+        if result.is_ok() {
+            self.reset_pending = false;
+        }
+        result
+    }
+}
@@ -0,0 +1,399 @@
+//! The optional validation of the evaluation inputs, reporting the
+//! misconfigurations the NGX only reports as
+//! [`nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter`]
+//! or [`nvngx_sys::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_MissingInput`].
+//!
+//! When enabled ([`crate::SuperSamplingFeature::set_validation_enabled`],
+//! [`crate::RayReconstructionFeature::set_validation_enabled`]), the
+//! evaluation fails with an error naming the offending input if:
+//!
+//! - a required input (the color input and output, the depth or the
+//!   motion vectors) is missing;
+//! - the color output isn't writable;
+//! - an input is smaller than the rendered region;
+//! - the color output doesn't match the target resolution;
+//! - a format isn't among the supported ones, including the depth
+//!   format for the depth convention ([`DlssFeatureFlags::depth_inverted`]).
+//!
+//! Some checks always run, whether the validation is enabled or not:
+//! the subrects fitting into their resources, the alpha formats with
+//! the alpha upscaling ([`DlssFeatureFlags::alpha_upscaling`]), and
+//! the Ray Reconstruction G-buffer and alpha inputs. These depend only
+//! on how the feature was created, and a wrong subrect makes the NGX
+//! access the resources outside of the intended region instead of
+//! failing. The checks above compare every input with the format lists
+//! of this crate instead, which may lag behind what a newer DLSS
+//! accepts, so they are opt-in.
+
+use super::*;
+
+/// The formats of the color inputs and outputs the features support.
+const SUPPORTED_COLOR_FORMATS: &[vk::Format] = &[
+    vk::Format::R8G8B8A8_UNORM,
+    vk::Format::R8G8B8A8_SRGB,
+    vk::Format::B8G8R8A8_UNORM,
+    vk::Format::B8G8R8A8_SRGB,
+    vk::Format::A2B10G10R10_UNORM_PACK32,
+    vk::Format::A2R10G10B10_UNORM_PACK32,
+    vk::Format::B10G11R11_UFLOAT_PACK32,
+    vk::Format::R16G16B16A16_SFLOAT,
+    vk::Format::R32G32B32A32_SFLOAT,
+];
+
+/// The color formats with an alpha channel suitable for the alpha
+/// upscaling: of at least 8 bits.
+const ALPHA_COLOR_FORMATS: &[vk::Format] = &[
+    vk::Format::R8G8B8A8_UNORM,
+    vk::Format::R8G8B8A8_SRGB,
+    vk::Format::B8G8R8A8_UNORM,
+    vk::Format::B8G8R8A8_SRGB,
+    vk::Format::R16G16B16A16_SFLOAT,
+    vk::Format::R32G32B32A32_SFLOAT,
+];
+
+/// The formats of the motion vectors the features support: at least
+/// two channels, in floating point.
+const SUPPORTED_MOTION_VECTORS_FORMATS: &[vk::Format] = &[
+    vk::Format::R16G16_SFLOAT,
+    vk::Format::R32G32_SFLOAT,
+    vk::Format::R16G16B16A16_SFLOAT,
+    vk::Format::R32G32B32A32_SFLOAT,
+];
+
+/// The floating-point depth formats the features support.
+const SUPPORTED_FLOAT_DEPTH_FORMATS: &[vk::Format] = &[
+    vk::Format::D32_SFLOAT,
+    vk::Format::D32_SFLOAT_S8_UINT,
+    vk::Format::R32_SFLOAT,
+    vk::Format::R16_SFLOAT,
+];
+
+/// The fixed-point depth formats the features support.
+const SUPPORTED_UNORM_DEPTH_FORMATS: &[vk::Format] = &[
+    vk::Format::D16_UNORM,
+    vk::Format::D24_UNORM_S8_UINT,
+    vk::Format::X8_D24_UNORM_PACK32,
+];
+
+/// The single-channel formats of the separate alpha input and output
+/// of the Ray Reconstruction.
+const SUPPORTED_ALPHA_FORMATS: &[vk::Format] = &[
+    vk::Format::R8_UNORM,
+    vk::Format::R16_UNORM,
+    vk::Format::R16_SFLOAT,
+    vk::Format::R32_SFLOAT,
+];
+
+/// The formats of the normals able to hold the roughness in the alpha
+/// channel, with the
+/// [`RoughnessMode::Packed`] roughness mode: of at least 8 bits.
+pub(crate) const PACKED_ROUGHNESS_NORMALS_FORMATS: &[vk::Format] = &[
+    vk::Format::R8G8B8A8_UNORM,
+    vk::Format::R8G8B8A8_SNORM,
+    vk::Format::R16G16B16A16_UNORM,
+    vk::Format::R16G16B16A16_SNORM,
+    vk::Format::R16G16B16A16_SFLOAT,
+    vk::Format::R32G32B32A32_SFLOAT,
+];
+
+/// The image an input or an output of the evaluation refers to.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ValidatedImage {
+    pub(crate) format: vk::Format,
+    pub(crate) extent: vk::Extent2D,
+    pub(crate) writable: bool,
+}
+
+/// Returns the image the `resource` refers to, or [`None`] if the
+/// `resource` isn't set. Fails if the `resource` isn't an image.
+pub(crate) fn get_image(
+    name: &str,
+    resource: *const NVSDK_NGX_Resource_VK,
+) -> Result<Option<ValidatedImage>> {
+    let Some(resource) = (unsafe { resource.as_ref() }) else {
+        return Ok(None);
+    };
+    if resource.Type != NVSDK_NGX_Resource_VK_Type::NVSDK_NGX_RESOURCE_VK_TYPE_VK_IMAGEVIEW {
+        return Err(nvngx_sys::Error::Other(format!(
+            "The {name} must be an image view, not a buffer."
+        )));
+    }
+    let image_view_info = unsafe { resource.Resource.ImageViewInfo.as_ref() };
+    Ok(Some(ValidatedImage {
+        format: image_view_info.Format,
+        extent: vk::Extent2D::default()
+            .width(image_view_info.Width)
+            .height(image_view_info.Height),
+        writable: resource.ReadWrite,
+    }))
+}
+
+/// Returns the image the `resource` refers to, failing if it isn't
+/// set.
+pub(crate) fn require_image(
+    name: &str,
+    resource: *const NVSDK_NGX_Resource_VK,
+) -> Result<ValidatedImage> {
+    get_image(name, resource)?
+        .ok_or_else(|| nvngx_sys::Error::Other(format!("The required {name} input is missing.")))
+}
+
+/// Fails if the format of the `image` isn't one of the `formats`.
+pub(crate) fn check_format(name: &str, image: &ValidatedImage, formats: &[vk::Format]) -> Result {
+    if formats.contains(&image.format) {
+        Ok(())
+    } else {
+        Err(nvngx_sys::Error::Other(format!(
+            "The format {:?} of the {name} isn't supported, expected one of {formats:?}.",
+            image.format
+        )))
+    }
+}
+
+/// Fails if the format of the `depth` isn't supported for the depth
+/// convention of the `flags`. Both the floating-point and the
+/// fixed-point formats are valid with either convention, however the
+/// inverted depth with a fixed-point format is logged as a warning, as
+/// the inverted depth only improves the precision with the
+/// floating-point formats.
+pub(crate) fn check_depth_format(depth: &ValidatedImage, flags: DlssFeatureFlags) -> Result {
+    let is_fixed_point = SUPPORTED_UNORM_DEPTH_FORMATS.contains(&depth.format);
+    if !is_fixed_point && !SUPPORTED_FLOAT_DEPTH_FORMATS.contains(&depth.format) {
+        let name = if flags.is_depth_inverted() {
+            "depth (inverted)"
+        } else {
+            "depth"
+        };
+        return Err(nvngx_sys::Error::Other(format!(
+            "The format {:?} of the {name} isn't supported, expected one of {SUPPORTED_FLOAT_DEPTH_FORMATS:?} or {SUPPORTED_UNORM_DEPTH_FORMATS:?}.",
+            depth.format
+        )));
+    }
+    if is_fixed_point && flags.is_depth_inverted() {
+        log::warn!(
+            "The depth format {:?} is fixed-point, the inverted depth doesn't improve its precision.",
+            depth.format
+        );
+    }
+    Ok(())
+}
+
+/// Fails if the `image` is smaller than the `size`.
+pub(crate) fn check_min_size(name: &str, image: &ValidatedImage, size: vk::Extent2D) -> Result {
+    if image.extent.width >= size.width && image.extent.height >= size.height {
+        Ok(())
+    } else {
+        Err(nvngx_sys::Error::Other(format!(
+            "The {name} is {}x{}, which is smaller than {}x{}.",
+            image.extent.width, image.extent.height, size.width, size.height
+        )))
+    }
+}
+
+/// Validates that the color input and output (if set) have an alpha
+/// channel to upscale.
+pub(crate) fn validate_alpha_formats(
+    color_input: *const NVSDK_NGX_Resource_VK,
+    color_output: *const NVSDK_NGX_Resource_VK,
+) -> Result {
+    for (name, resource) in [("color input", color_input), ("color output", color_output)] {
+        if let Some(image) = get_image(name, resource)? {
+            check_format(
+                &format!("{name} (with the alpha upscaling enabled)"),
+                &image,
+                ALPHA_COLOR_FORMATS,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Validates the separate alpha input and output: both or neither are
+/// set, in a single-channel format, and the output is writable.
+pub(crate) fn validate_alpha_input_output(
+    alpha_input: *const NVSDK_NGX_Resource_VK,
+    alpha_output: *const NVSDK_NGX_Resource_VK,
+) -> Result {
+    let alpha_input = get_image("alpha input", alpha_input)?;
+    let alpha_output = get_image("alpha output", alpha_output)?;
+    let (alpha_input, alpha_output) = match (alpha_input, alpha_output) {
+        (None, None) => return Ok(()),
+        (Some(alpha_input), Some(alpha_output)) => (alpha_input, alpha_output),
+        _ => {
+            return Err(nvngx_sys::Error::Other(
+                "Both the alpha input and the alpha output must be set.".to_owned(),
+            ))
+        }
+    };
+    check_format("alpha input", &alpha_input, SUPPORTED_ALPHA_FORMATS)?;
+    check_format("alpha output", &alpha_output, SUPPORTED_ALPHA_FORMATS)?;
+    if !alpha_output.writable {
+        return Err(nvngx_sys::Error::Other(
+            "The alpha output must be writable (see VkImageResourceDescription::set_writable)."
+                .to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates the inputs every temporal upscaler requires: the color
+/// input and output, the depth and the motion vectors.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn validate_common_inputs(
+    color_input: *const NVSDK_NGX_Resource_VK,
+    color_output: *const NVSDK_NGX_Resource_VK,
+    depth: *const NVSDK_NGX_Resource_VK,
+    motion_vectors: *const NVSDK_NGX_Resource_VK,
+    render_size: vk::Extent2D,
+    target_size: vk::Extent2D,
+    flags: DlssFeatureFlags,
+    output_subrects_enabled: bool,
+) -> Result {
+    let color_input = require_image("color input", color_input)?;
+    check_format("color input", &color_input, SUPPORTED_COLOR_FORMATS)?;
+    check_min_size("color input", &color_input, render_size)?;
+
+    let color_output = require_image("color output", color_output)?;
+    check_format("color output", &color_output, SUPPORTED_COLOR_FORMATS)?;
+    if !color_output.writable {
+        return Err(nvngx_sys::Error::Other(
+            "The color output must be writable (see VkImageResourceDescription::set_writable)."
+                .to_owned(),
+        ));
+    }
+    // With the output subrects enabled, the output may be bigger, which
+    // is checked along with the subrects.
+    if !output_subrects_enabled && color_output.extent != target_size {
+        return Err(nvngx_sys::Error::Other(format!(
+            "The color output is {}x{}, while the target resolution is {}x{}.",
+            color_output.extent.width,
+            color_output.extent.height,
+            target_size.width,
+            target_size.height
+        )));
+    }
+
+    let depth = require_image("depth", depth)?;
+    check_depth_format(&depth, flags)?;
+    check_min_size("depth", &depth, render_size)?;
+
+    let motion_vectors = require_image("motion vectors", motion_vectors)?;
+    check_format(
+        "motion vectors",
+        &motion_vectors,
+        SUPPORTED_MOTION_VECTORS_FORMATS,
+    )?;
+    let motion_vectors_size = if flags.is_mv_low_res() {
+        render_size
+    } else {
+        target_size
+    };
+    check_min_size("motion vectors", &motion_vectors, motion_vectors_size)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(format: vk::Format, width: u32, height: u32) -> NVSDK_NGX_Resource_VK {
+        VkImageResourceDescription {
+            format,
+            width,
+            height,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn common_inputs() {
+        let target_size = vk::Extent2D::default().width(128).height(128);
+        let color_input = image(vk::Format::R16G16B16A16_SFLOAT, 64, 64);
+        let mut color_output = image(vk::Format::R16G16B16A16_SFLOAT, 128, 128);
+        let render_size = vk::Extent2D::default().width(64).height(64);
+        let depth = image(vk::Format::D24_UNORM_S8_UINT, 64, 64);
+        let motion_vectors = image(vk::Format::R16G16_SFLOAT, 128, 128);
+        let validate = |color_output: &NVSDK_NGX_Resource_VK, flags| {
+            validate_common_inputs(
+                &color_input,
+                color_output,
+                &depth,
+                &motion_vectors,
+                render_size,
+                target_size,
+                flags,
+                false,
+            )
+        };
+
+        let error = validate(&color_output, DlssFeatureFlags::new()).unwrap_err();
+        assert!(error.to_string().contains("color output must be writable"));
+
+        color_output.ReadWrite = true;
+        assert!(validate(&color_output, DlssFeatureFlags::new()).is_ok());
+
+        // The reverse-Z works with the fixed-point depth as well.
+        assert!(validate(&color_output, DlssFeatureFlags::new().depth_inverted(true)).is_ok());
+
+        let error = validate_common_inputs(
+            &color_input,
+            &color_output,
+            std::ptr::null(),
+            &motion_vectors,
+            render_size,
+            target_size,
+            DlssFeatureFlags::new(),
+            false,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("depth input is missing"));
+    }
+
+    #[test]
+    fn depth_formats() {
+        let check = |format, flags| {
+            let depth = image(format, 64, 64);
+            let depth = get_image("depth", &depth).unwrap().unwrap();
+            check_depth_format(&depth, flags)
+        };
+        let inverted = DlssFeatureFlags::new().depth_inverted(true);
+
+        for format in [vk::Format::D32_SFLOAT, vk::Format::D24_UNORM_S8_UINT] {
+            assert!(check(format, DlssFeatureFlags::new()).is_ok());
+            assert!(check(format, inverted).is_ok());
+        }
+        let error = check(vk::Format::R8_UNORM, inverted).unwrap_err();
+        assert!(error.to_string().contains("depth (inverted)"));
+        let error = check(vk::Format::R16G16_SFLOAT, DlssFeatureFlags::new()).unwrap_err();
+        assert!(error.to_string().contains("R16G16_SFLOAT"));
+    }
+
+    #[test]
+    fn alpha_formats() {
+        let rgba = image(vk::Format::R16G16B16A16_SFLOAT, 64, 64);
+        let rgb = image(vk::Format::B10G11R11_UFLOAT_PACK32, 64, 64);
+        assert!(validate_alpha_formats(&rgba, &rgba).is_ok());
+        assert!(validate_alpha_formats(&rgba, std::ptr::null()).is_ok());
+        let error = validate_alpha_formats(&rgba, &rgb).unwrap_err();
+        assert!(error.to_string().contains("color output"));
+    }
+
+    #[test]
+    fn alpha_input_output() {
+        let alpha_input = image(vk::Format::R8_UNORM, 64, 64);
+        let mut alpha_output = image(vk::Format::R16_SFLOAT, 128, 128);
+        assert!(validate_alpha_input_output(std::ptr::null(), std::ptr::null()).is_ok());
+        let error = validate_alpha_input_output(&alpha_input, std::ptr::null()).unwrap_err();
+        assert!(error.to_string().contains("Both"));
+        let error = validate_alpha_input_output(&alpha_input, &alpha_output).unwrap_err();
+        assert!(error.to_string().contains("writable"));
+
+        alpha_output.ReadWrite = true;
+        assert!(validate_alpha_input_output(&alpha_input, &alpha_output).is_ok());
+        let rgba = image(vk::Format::R16G16B16A16_SFLOAT, 64, 64);
+        let error = validate_alpha_input_output(&rgba, &alpha_output).unwrap_err();
+        assert!(error.to_string().contains("alpha input"));
+    }
+}